@@ -0,0 +1,11 @@
+#!/bin/sh
+# Builds resize.wasm with SIMD128 (see .cargo/config.toml) and resize_scalar.wasm
+# without it, for the engines without SIMD support, and their brotli versions.
+set -e
+cd "$(dirname "$0")"
+wasm-pack build --target web --release --out-dir target/pkg
+RUSTFLAGS="-Ctarget-feature=-simd128" CARGO_TARGET_DIR=target/scalar \
+  wasm-pack build --target web --release --out-dir target/pkg_scalar
+cp target/pkg/wasm_resize_rgba_bg.wasm resize.wasm
+cp target/pkg_scalar/wasm_resize_rgba_bg.wasm resize_scalar.wasm
+brotli -f -q 11 resize.wasm resize_scalar.wasm
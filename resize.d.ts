@@ -1,6 +1,38 @@
 /* tslint:disable */
 /* eslint-disable */
+/**
+ * Every function throws an Error if a dimension is zero or too big,
+ * or if the length of an array doesn't match its dimensions.
+ */
 declare module 'resize' {
+  /**
+   * Name of a convolution filter.
+   */
+  export type Filter = 'box'|'bilinear'|'hamming'|'catmullrom'|'mitchell'|'lanczos3';
+  /**
+   * Name of a resize algorithm: nearest for nearest neighbour, a convolution filter,
+   * or supersampling:<filter>:<multiplicity> (e.g. supersampling:lanczos3:2) to reduce the image
+   * with nearest neighbour to multiplicity times the target size before the filter, for large downscales.
+   * true and false stand for catmullrom and hamming where an algorithm is expected.
+   * An Error is thrown if the name is unknown or if the multiplicity is zero.
+   */
+  export type Algorithm = 'nearest'|Filter|`supersampling:${Filter}:${number}`;
+  /**
+   * Order of the channels of an array with four channels per pixel.
+   * An Error is thrown if the name is unknown.
+   */
+  export type PixelLayout = 'rgba'|'bgra'|'argb'|'abgr';
+  /**
+   * Name of CPU extensions used by the module.
+   */
+  export type CpuExtensions = 'simd128'|'none';
+  /**
+   * Returns the name of the CPU extensions used by the loaded module:
+   * simd128, or none for the scalar build loaded by engines without SIMD support.
+   * It only reports the extensions, which can't be selected.
+   * @return {CpuExtensions}
+   */
+  export function cpuExtensions(): CpuExtensions;
   /**
    * Resizes the supplied ImageData rgba array.
    * @param {Uint8Array} data
@@ -8,14 +40,562 @@ declare module 'resize' {
    * @param {number} sourceHeight
    * @param {number} targetWidth
    * @param {number} targetHeight
-   * @param {boolean} [hq=true]
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @param {boolean} [linearLight=false] resizes in linear light instead of on the sRGB values.
    * @return {Uint8Array}
    */
   export function resize(
     data: Uint8Array,
     sourceWidth: number, sourceHeight: number,
     targetWidth: number, targetHeight: number,
-    hq: boolean
+    algorithm?: Algorithm|boolean,
+    linearLight?: boolean
+  ): Uint8Array;
+  /**
+   * Resizes the supplied ImageData rgba array into the supplied target array.
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {Uint8Array} target
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @param {boolean} [linearLight=false] resizes in linear light instead of on the sRGB values.
+   */
+  export function resizeInto(
+    data: Uint8Array,
+    sourceWidth: number, sourceHeight: number,
+    target: Uint8Array,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean,
+    linearLight?: boolean
+  ): void;
+  /**
+   * Resizes the supplied ImageData rgba array, but computes only the rectangle of the result
+   * with the given position and dimensions, which is equal to the same rectangle of the whole result
+   * (e.g. to split a resize between workers, or to update only a part of an image).
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {number} left
+   * @param {number} top
+   * @param {number} width
+   * @param {number} height
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @param {boolean} [linearLight=false] resizes in linear light instead of on the sRGB values.
+   * @return {Uint8Array} the rgba array of the rectangle.
+   * @throws {Error} if the rectangle is outside of the result.
+   */
+  export function resizeRegion(
+    data: Uint8Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    left: number, top: number,
+    width: number, height: number,
+    algorithm?: Algorithm|boolean,
+    linearLight?: boolean
+  ): Uint8Array;
+  /**
+   * Resizes the supplied grayscale array (one byte per pixel).
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @return {Uint8Array}
+   */
+  export function resizeGray(
+    data: Uint8Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean
   ): Uint8Array;
+  /**
+   * Resizes the supplied grayscale array with alpha (two bytes per pixel, luma then alpha).
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @return {Uint8Array}
+   */
+  export function resizeGrayAlpha(
+    data: Uint8Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean
+  ): Uint8Array;
+  /**
+   * Resizes the supplied packed rgb array (three bytes per pixel).
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @return {Uint8Array}
+   */
+  export function resizeRgb(
+    data: Uint8Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean
+  ): Uint8Array;
+  /**
+   * Resizes the supplied rgba array with 16 bits per channel.
+   * @param {Uint16Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @return {Uint16Array}
+   */
+  export function resizeRgbaU16(
+    data: Uint16Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean
+  ): Uint16Array;
+  /**
+   * Resizes the supplied RGB565 array (one u16 per pixel, red in the most significant bits).
+   * @param {Uint16Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @param {boolean} [dithering=false] dithers the channels with an ordered pattern instead of rounding them.
+   * @return {Uint16Array}
+   */
+  export function resizeRgb565(
+    data: Uint16Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean,
+    dithering?: boolean
+  ): Uint16Array;
+  /**
+   * Resizes the supplied RGBA4444 array (one u16 per pixel, red in the most significant bits).
+   * @param {Uint16Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @param {boolean} [dithering=false] dithers the color channels with an ordered pattern instead of rounding them.
+   * @return {Uint16Array}
+   */
+  export function resizeRgba4444(
+    data: Uint16Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean,
+    dithering?: boolean
+  ): Uint16Array;
+  /**
+   * Resizes the supplied grayscale array of floats (one per pixel); values are neither clamped nor rounded.
+   * @param {Float32Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @return {Float32Array}
+   */
+  export function resizeGrayF32(
+    data: Float32Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean
+  ): Float32Array;
+  /**
+   * Resizes the supplied rgba array of floats (four per pixel); values are neither clamped nor rounded.
+   * @param {Float32Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @return {Float32Array}
+   */
+  export function resizeRgbaF32(
+    data: Float32Array,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean
+  ): Float32Array;
+  /**
+   * Resizes the supplied Y, U and V planes of an I420 image (e.g. copied from a VideoFrame),
+   * each with its own stride in bytes. Chroma planes have half the dimensions of the luma plane, rounded up.
+   * @param {Uint8Array} y
+   * @param {number} yStride
+   * @param {Uint8Array} u
+   * @param {number} uStride
+   * @param {Uint8Array} v
+   * @param {number} vStride
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @return {Uint8Array} the resized Y, U and V planes one after another, without padding.
+   * @throws {Error} if a plane doesn't fit into its array with its stride.
+   */
+  export function resizeI420(
+    y: Uint8Array, yStride: number,
+    u: Uint8Array, uStride: number,
+    v: Uint8Array, vStride: number,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean
+  ): Uint8Array;
+  /**
+   * Resizes the supplied Y and interleaved UV planes of an NV12 image (e.g. copied from a VideoFrame),
+   * each with its own stride in bytes. The chroma plane has half the dimensions of the luma plane, rounded up.
+   * @param {Uint8Array} y
+   * @param {number} yStride
+   * @param {Uint8Array} uv
+   * @param {number} uvStride
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @return {Uint8Array} the resized Y and UV planes one after another, without padding.
+   * @throws {Error} if a plane doesn't fit into its array with its stride
+   * or if the stride of the UV plane is odd.
+   */
+  export function resizeNv12(
+    y: Uint8Array, yStride: number,
+    uv: Uint8Array, uvStride: number,
+    sourceWidth: number, sourceHeight: number,
+    targetWidth: number, targetHeight: number,
+    algorithm?: Algorithm|boolean
+  ): Uint8Array;
+  /**
+   * Multiplies the rgb channels of the supplied ImageData rgba array by its alpha channel, in place.
+   * @param {Uint8Array|Uint8ClampedArray} data
+   * @param {number} width
+   * @param {number} height
+   * @param {PixelLayout} [layout='rgba'] the order of the channels.
+   */
+  export function multiplyAlpha(data: Uint8Array|Uint8ClampedArray, width: number, height: number, layout?: PixelLayout): void;
+  /**
+   * Divides the rgb channels of the supplied ImageData rgba array by its alpha channel, in place.
+   * @param {Uint8Array|Uint8ClampedArray} data
+   * @param {number} width
+   * @param {number} height
+   * @param {PixelLayout} [layout='rgba'] the order of the channels.
+   */
+  export function divideAlpha(data: Uint8Array|Uint8ClampedArray, width: number, height: number, layout?: PixelLayout): void;
+  /**
+   * Reusable resizer, that keeps its temporary buffer and its coefficients between calls.
+   * It should be released with free() once it's not needed anymore.
+   */
+  export class Resizer {
+    /**
+     * @param {Algorithm|boolean} [algorithm='catmullrom']
+     */
+    constructor(algorithm?: Algorithm|boolean);
+    /**
+     * Declares that the next source arrays are already multiplied by alpha.
+     * By default, they are multiplied before resizing to avoid dark halos around transparent areas.
+     * @param {boolean} premultiplied
+     */
+    setPremultipliedInput(premultiplied: boolean): void;
+    /**
+     * Keeps the next results multiplied by alpha (e.g. for WebGL uploads).
+     * By default, they are divided by alpha after resizing.
+     * @param {boolean} premultiplied
+     */
+    setPremultipliedOutput(premultiplied: boolean): void;
+    /**
+     * Dithers the channels of the next RGB565 and RGBA4444 results with an ordered pattern
+     * instead of rounding them, which avoids banding in smooth gradients.
+     * @param {boolean} dithering
+     */
+    setDithering(dithering: boolean): void;
+    /**
+     * Declares the order of the channels of the next source arrays and of the results.
+     * Alpha is handled according to them, and the channels are reordered if they differ.
+     * Both are rgba by default.
+     * @param {PixelLayout} input
+     * @param {PixelLayout} [output=input]
+     */
+    setPixelLayout(input: PixelLayout, output?: PixelLayout): void;
+    /**
+     * Resizes the next images in linear light instead of on the sRGB values,
+     * which keeps the brightness of high-contrast details such as text.
+     * @param {boolean} linearLight
+     */
+    setLinearLight(linearLight: boolean): void;
+    /**
+     * Restricts the next resizes to a sub-rectangle of the source image.
+     * Fractional values are allowed.
+     * @param {number} left
+     * @param {number} top
+     * @param {number} width
+     * @param {number} height
+     */
+    setCrop(left: number, top: number, width: number, height: number): void;
+    /**
+     * Removes the crop rectangle, so that the next resizes use the whole source image.
+     */
+    clearCrop(): void;
+    /**
+     * Resizes the supplied ImageData rgba array.
+     * @param {Uint8Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint8Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resize(
+      data: Uint8Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint8Array;
+    /**
+     * Resizes the supplied ImageData rgba array, but computes only the rectangle of the result
+     * with the given position and dimensions, which is equal to the same rectangle of the whole result.
+     * @param {Uint8Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @param {number} left
+     * @param {number} top
+     * @param {number} width
+     * @param {number} height
+     * @return {Uint8Array} the rgba array of the rectangle.
+     * @throws {Error} if the crop rectangle is outside of the source image or if the rectangle is outside of the result.
+     */
+    resizeRegion(
+      data: Uint8Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number,
+      left: number, top: number,
+      width: number, height: number
+    ): Uint8Array;
+    /**
+     * Resizes the supplied grayscale array (one byte per pixel).
+     * @param {Uint8Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint8Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resizeGray(
+      data: Uint8Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint8Array;
+    /**
+     * Resizes the supplied grayscale array with alpha (two bytes per pixel, luma then alpha).
+     * @param {Uint8Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint8Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resizeGrayAlpha(
+      data: Uint8Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint8Array;
+    /**
+     * Resizes the supplied packed rgb array (three bytes per pixel).
+     * @param {Uint8Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint8Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resizeRgb(
+      data: Uint8Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint8Array;
+    /**
+     * Resizes the supplied rgba array with 16 bits per channel.
+     * @param {Uint16Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint16Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resizeRgbaU16(
+      data: Uint16Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint16Array;
+    /**
+     * Resizes the supplied RGB565 array (one u16 per pixel, red in the most significant bits).
+     * @param {Uint16Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint16Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resizeRgb565(
+      data: Uint16Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint16Array;
+    /**
+     * Resizes the supplied RGBA4444 array (one u16 per pixel, red in the most significant bits).
+     * @param {Uint16Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint16Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resizeRgba4444(
+      data: Uint16Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint16Array;
+    /**
+     * Resizes the supplied grayscale array of floats (one per pixel); values are neither clamped nor rounded.
+     * @param {Float32Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Float32Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resizeGrayF32(
+      data: Float32Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Float32Array;
+    /**
+     * Resizes the supplied rgba array of floats (four per pixel); values are neither clamped nor rounded.
+     * @param {Float32Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Float32Array}
+     * @throws {Error} if the crop rectangle is outside of the source image.
+     */
+    resizeRgbaF32(
+      data: Float32Array,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Float32Array;
+    /**
+     * Resizes the supplied Y, U and V planes of an I420 image (e.g. copied from a VideoFrame),
+     * each with its own stride in bytes. Chroma planes have half the dimensions of the luma plane, rounded up.
+     * @param {Uint8Array} y
+     * @param {number} yStride
+     * @param {Uint8Array} u
+     * @param {number} uStride
+     * @param {Uint8Array} v
+     * @param {number} vStride
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint8Array} the resized Y, U and V planes one after another, without padding.
+     * @throws {Error} if a plane doesn't fit into its array with its stride
+     * or if the crop rectangle is outside of the source image.
+     */
+    resizeI420(
+      y: Uint8Array, yStride: number,
+      u: Uint8Array, uStride: number,
+      v: Uint8Array, vStride: number,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint8Array;
+    /**
+     * Resizes the supplied Y and interleaved UV planes of an NV12 image (e.g. copied from a VideoFrame),
+     * each with its own stride in bytes. The chroma plane has half the dimensions of the luma plane, rounded up.
+     * @param {Uint8Array} y
+     * @param {number} yStride
+     * @param {Uint8Array} uv
+     * @param {number} uvStride
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint8Array} the resized Y and UV planes one after another, without padding.
+     * @throws {Error} if a plane doesn't fit into its array with its stride,
+     * if the stride of the UV plane is odd or if the crop rectangle is outside of the source image.
+     */
+    resizeNv12(
+      y: Uint8Array, yStride: number,
+      uv: Uint8Array, uvStride: number,
+      sourceWidth: number, sourceHeight: number,
+      targetWidth: number, targetHeight: number
+    ): Uint8Array;
+    /**
+     * Resizes the supplied ImageData rgba array into the supplied target array.
+     * @param {Uint8Array} data
+     * @param {number} sourceWidth
+     * @param {number} sourceHeight
+     * @param {Uint8Array} target
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     */
+    resizeInto(
+      data: Uint8Array,
+      sourceWidth: number, sourceHeight: number,
+      target: Uint8Array,
+      targetWidth: number, targetHeight: number
+    ): void;
+    /**
+     * Returns a view on a region of wasm memory, kept by this resizer, for a source
+     * rgba image of the given dimensions. The pixels should be written directly into it.
+     * @param {number} width
+     * @param {number} height
+     * @return {Uint8Array}
+     */
+    sourceBuffer(width: number, height: number): Uint8Array;
+    /**
+     * Resizes the image written in the source buffer, without any copy, and returns
+     * a view on the result, which is also kept in wasm memory by this resizer.
+     * @param {number} targetWidth
+     * @param {number} targetHeight
+     * @return {Uint8Array}
+     * @throws {Error} if there's no source buffer.
+     */
+    resizeSourceBuffer(targetWidth: number, targetHeight: number): Uint8Array;
+    /**
+     * View on the source buffer, or null before the first call to sourceBuffer().
+     * A view is detached when the wasm memory grows, which any call to the module
+     * may do, so a new one is created from the current memory on each access.
+     */
+    readonly source: Uint8Array | null;
+    /**
+     * View on the result of the last call to resizeSourceBuffer(), or null before it.
+     * It is created again on each access, like the source view.
+     */
+    readonly destination: Uint8Array | null;
+    /**
+     * Releases the wasm memory used by this resizer.
+     */
+    free(): void;
+  }
   export default resize;
 }
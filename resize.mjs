@@ -1,12 +1,16 @@
-const url=new URL('resize.wasm',import.meta.url);
+import './resize_wasm_file.js';
+const url=new URL(globalThis.resizeWasmFile,import.meta.url);
 let wasm;
+const text=(p,n)=>new TextDecoder().decode(new Uint8Array(wasm.memory.buffer).subarray(p,p+n));
 const imports={
-  wbg:{
-    __wbg_log_cb485ef4b8fcc41d:(p,n)=>{
-      console.log(new TextDecoder().decode(new Uint8Array(wasm.memory.buffer).subarray(p,p+n)));
+  './wasm_resize_rgba_bg.js':{
+    __wbg_Error_30c8987f7c2ed4e2:(p,n)=>Error(text(p,n)),
+    __wbg___wbindgen_copy_to_typed_array_88899a52af046901:(p,n,a)=>{
+      new Uint8Array(a.buffer,a.byteOffset,a.byteLength).set(new Uint8Array(wasm.memory.buffer).subarray(p,p+n));
     },
+    __wbg___wbindgen_throw_41e9ee4f547fc59a:(p,n)=>{throw new Error(text(p,n))},
     __wbindgen_init_externref_table:function(){
-      const table=wasm.__wbindgen_export_0;
+      const table=wasm.__wbindgen_externrefs;
       const offset=table.grow(4);
       table.set(0);
       table.set(offset);
@@ -18,8 +22,62 @@ const imports={
 };
 const {instance}=await WebAssembly.instantiateStreaming(await fetch(url,{cache: 'force-cache'}),imports);
 wasm=instance.exports;
+wasm.__wbindgen_start();
 const malloc=wasm.__wbindgen_malloc;
 const free=wasm.__wbindgen_free;
+// Every function throws an Error if a dimension is zero or too big,
+// or if the length of an array doesn't match its dimensions.
+const error=i=>{
+  const e=wasm.__wbindgen_externrefs.get(i);
+  wasm.__externref_table_dealloc(i);
+  return e;
+};
+const copy=data=>{
+  const n=data.length;
+  const p=malloc(n,1);
+  new Uint8Array(wasm.memory.buffer).set(data,p);
+  return [p,n];
+};
+const string=s=>copy(new TextEncoder().encode(s));
+/**
+ * Name of a resize algorithm: nearest for nearest neighbour, one of the convolution
+ * filters box, bilinear, hamming, catmullrom, mitchell or lanczos3,
+ * or supersampling:<filter>:<multiplicity> (e.g. supersampling:lanczos3:2) to reduce the image
+ * with nearest neighbour to multiplicity times the target size before the filter, for large downscales.
+ * true and false stand for catmullrom and hamming.
+ * An Error is thrown if the name is unknown or if the multiplicity is zero.
+ * @typedef {'nearest'|'box'|'bilinear'|'hamming'|'catmullrom'|'mitchell'|'lanczos3'|`supersampling:${string}:${number}`|boolean} Algorithm
+ */
+/**
+ * Order of the channels of an array with four channels per pixel.
+ * An Error is thrown if the name is unknown.
+ * @typedef {'rgba'|'bgra'|'argb'|'abgr'} PixelLayout
+ */
+const algorithmName=algorithm=>algorithm===true?'catmullrom':algorithm===false?'hamming':algorithm;
+const result=([p,n,e,failed])=>{
+  if(failed) throw error(e);
+  const res=new Uint8Array(wasm.memory.buffer).subarray(p,p+n).slice();
+  free(p,n,1);
+  return res;
+};
+const copyArray=Type=>data=>{
+  const n=data.length;
+  const size=Type.BYTES_PER_ELEMENT;
+  const p=malloc(n*size,size);
+  new Type(wasm.memory.buffer).set(data,p/size);
+  return [p,n];
+};
+const resultArray=Type=>([p,n,e,failed])=>{
+  if(failed) throw error(e);
+  const size=Type.BYTES_PER_ELEMENT;
+  const res=new Type(wasm.memory.buffer).subarray(p/size,p/size+n).slice();
+  free(p,n*size,size);
+  return res;
+};
+const copy16=copyArray(Uint16Array);
+const result16=resultArray(Uint16Array);
+const copyF32=copyArray(Float32Array);
+const resultF32=resultArray(Float32Array);
 /**
  * Resizes the supplied ImageData rgba array.
  * @param {Uint8Array} data
@@ -27,17 +85,534 @@ const free=wasm.__wbindgen_free;
  * @param {number} sourceHeight
  * @param {number} targetWidth
  * @param {number} targetHeight
- * @param {boolean} hq
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @param {boolean} [linearLight=false] resizes in linear light instead of on the sRGB values.
+ * @return {Uint8Array}
+ */
+const resize=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom',linearLight=false)=>
+  result(wasm.resize(...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm)),linearLight));
+/**
+ * Resizes the supplied ImageData rgba array into the supplied target array.
+ * @param {Uint8Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {Uint8Array} target
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @param {boolean} [linearLight=false] resizes in linear light instead of on the sRGB values.
+ */
+const resizeInto=(data,sourceWidth,sourceHeight,target,targetWidth,targetHeight,algorithm='catmullrom',linearLight=false)=>{
+  const [e,failed]=wasm.resizeInto(...copy(data),sourceWidth,sourceHeight,...copy(target),target,targetWidth,targetHeight,...string(algorithmName(algorithm)),linearLight);
+  if(failed) throw error(e);
+};
+/**
+ * Resizes the supplied ImageData rgba array, but computes only the rectangle of the result
+ * with the given position and dimensions, which is equal to the same rectangle of the whole result
+ * (e.g. to split a resize between workers, or to update only a part of an image).
+ * @param {Uint8Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {number} left
+ * @param {number} top
+ * @param {number} width
+ * @param {number} height
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @param {boolean} [linearLight=false] resizes in linear light instead of on the sRGB values.
+ * @return {Uint8Array} the rgba array of the rectangle.
+ * @throws {Error} if the rectangle is outside of the result.
+ */
+const resizeRegion=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,left,top,width,height,algorithm='catmullrom',linearLight=false)=>
+  result(wasm.resizeRegion(...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight,left,top,width,height,...string(algorithmName(algorithm)),linearLight));
+/**
+ * Resizes the supplied grayscale array (one byte per pixel).
+ * @param {Uint8Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @return {Uint8Array}
+ */
+const resizeGray=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom')=>
+  result(wasm.resizeGray(...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm))));
+/**
+ * Resizes the supplied grayscale array with alpha (two bytes per pixel, luma then alpha).
+ * @param {Uint8Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @return {Uint8Array}
+ */
+const resizeGrayAlpha=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom')=>
+  result(wasm.resizeGrayAlpha(...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm))));
+/**
+ * Resizes the supplied packed rgb array (three bytes per pixel).
+ * @param {Uint8Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
  * @return {Uint8Array}
  */
-const resize=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,hq=true)=>{
-  const n1=data.length;
-  const p1=malloc(n1,1);
-  new Uint8Array(wasm.memory.buffer).set(data,p1);
-  const [p2,n2]=wasm.resize(p1,n1,sourceWidth,sourceHeight,targetWidth,targetHeight,hq);
-  const res=new Uint8Array(wasm.memory.buffer).subarray(p2,p2+n2).slice();
-  free(p2,n2);
+const resizeRgb=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom')=>
+  result(wasm.resizeRgb(...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm))));
+/**
+ * Resizes the supplied rgba array with 16 bits per channel.
+ * @param {Uint16Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @return {Uint16Array}
+ */
+const resizeRgbaU16=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom')=>
+  result16(wasm.resizeRgbaU16(...copy16(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm))));
+/**
+ * Resizes the supplied RGB565 array (one u16 per pixel, red in the most significant bits).
+ * @param {Uint16Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @param {boolean} [dithering=false] dithers the channels with an ordered pattern instead of rounding them.
+ * @return {Uint16Array}
+ */
+const resizeRgb565=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom',dithering=false)=>
+  result16(wasm.resizeRgb565(...copy16(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm)),dithering));
+/**
+ * Resizes the supplied RGBA4444 array (one u16 per pixel, red in the most significant bits).
+ * @param {Uint16Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @param {boolean} [dithering=false] dithers the color channels with an ordered pattern instead of rounding them.
+ * @return {Uint16Array}
+ */
+const resizeRgba4444=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom',dithering=false)=>
+  result16(wasm.resizeRgba4444(...copy16(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm)),dithering));
+/**
+ * Resizes the supplied grayscale array of floats (one per pixel); values are neither clamped nor rounded.
+ * @param {Float32Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @return {Float32Array}
+ */
+const resizeGrayF32=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom')=>
+  resultF32(wasm.resizeGrayF32(...copyF32(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm))));
+/**
+ * Resizes the supplied rgba array of floats (four per pixel); values are neither clamped nor rounded.
+ * @param {Float32Array} data
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @return {Float32Array}
+ */
+const resizeRgbaF32=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom')=>
+  resultF32(wasm.resizeRgbaF32(...copyF32(data),sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm))));
+/**
+ * Resizes the supplied Y, U and V planes of an I420 image (e.g. copied from a VideoFrame),
+ * each with its own stride in bytes. Chroma planes have half the dimensions of the luma plane, rounded up.
+ * @param {Uint8Array} y
+ * @param {number} yStride
+ * @param {Uint8Array} u
+ * @param {number} uStride
+ * @param {Uint8Array} v
+ * @param {number} vStride
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @return {Uint8Array} the resized Y, U and V planes one after another, without padding.
+ * @throws {Error} if a plane doesn't fit into its array with its stride.
+ */
+const resizeI420=(y,yStride,u,uStride,v,vStride,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom')=>
+  result(wasm.resizeI420(...copy(y),yStride,...copy(u),uStride,...copy(v),vStride,sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm))));
+/**
+ * Resizes the supplied Y and interleaved UV planes of an NV12 image (e.g. copied from a VideoFrame),
+ * each with its own stride in bytes. The chroma plane has half the dimensions of the luma plane, rounded up.
+ * @param {Uint8Array} y
+ * @param {number} yStride
+ * @param {Uint8Array} uv
+ * @param {number} uvStride
+ * @param {number} sourceWidth
+ * @param {number} sourceHeight
+ * @param {number} targetWidth
+ * @param {number} targetHeight
+ * @param {Algorithm} [algorithm='catmullrom']
+ * @return {Uint8Array} the resized Y and UV planes one after another, without padding.
+ * @throws {Error} if a plane doesn't fit into its array with its stride
+ * or if the stride of the UV plane is odd.
+ */
+const resizeNv12=(y,yStride,uv,uvStride,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom')=>
+  result(wasm.resizeNv12(...copy(y),yStride,...copy(uv),uvStride,sourceWidth,sourceHeight,targetWidth,targetHeight,...string(algorithmName(algorithm))));
+/**
+ * Multiplies the rgb channels of the supplied ImageData rgba array by its alpha channel, in place.
+ * @param {Uint8Array|Uint8ClampedArray} data
+ * @param {number} width
+ * @param {number} height
+ * @param {PixelLayout} [layout='rgba'] the order of the channels.
+ */
+const multiplyAlpha=(data,width,height,layout='rgba')=>{
+  const [e,failed]=wasm.multiplyAlpha(...copy(data),data,width,height,...string(layout));
+  if(failed) throw error(e);
+};
+/**
+ * Divides the rgb channels of the supplied ImageData rgba array by its alpha channel, in place.
+ * @param {Uint8Array|Uint8ClampedArray} data
+ * @param {number} width
+ * @param {number} height
+ * @param {PixelLayout} [layout='rgba'] the order of the channels.
+ */
+const divideAlpha=(data,width,height,layout='rgba')=>{
+  const [e,failed]=wasm.divideAlpha(...copy(data),data,width,height,...string(layout));
+  if(failed) throw error(e);
+};
+const registry=new FinalizationRegistry(p=>wasm.__wbg_resizer_free(p,0));
+/**
+ * Reusable resizer, that keeps its temporary buffer and its coefficients between calls.
+ * It should be released with free() once it's not needed anymore.
+ */
+class Resizer{
+  #ptr;
+  #source=null;
+  #destination=null;
+  /**
+   * @param {Algorithm} [algorithm='catmullrom']
+   */
+  constructor(algorithm='catmullrom'){
+    const [p,e,failed]=wasm.resizer_with_algorithm(...string(algorithmName(algorithm)));
+    if(failed) throw error(e);
+    this.#ptr=p;
+    registry.register(this,this.#ptr,this);
+  }
+  /**
+   * Declares that the next source arrays are already multiplied by alpha.
+   * By default, they are multiplied before resizing to avoid dark halos around transparent areas.
+   * @param {boolean} premultiplied
+   */
+  setPremultipliedInput(premultiplied){
+    wasm.resizer_setPremultipliedInput(this.#ptr,premultiplied);
+  }
+  /**
+   * Keeps the next results multiplied by alpha (e.g. for WebGL uploads).
+   * By default, they are divided by alpha after resizing.
+   * @param {boolean} premultiplied
+   */
+  setPremultipliedOutput(premultiplied){
+    wasm.resizer_setPremultipliedOutput(this.#ptr,premultiplied);
+  }
+  /**
+   * Dithers the channels of the next RGB565 and RGBA4444 results with an ordered pattern
+   * instead of rounding them, which avoids banding in smooth gradients.
+   * @param {boolean} dithering
+   */
+  setDithering(dithering){
+    wasm.resizer_setDithering(this.#ptr,dithering);
+  }
+  /**
+   * Declares the order of the channels of the next source arrays and of the results.
+   * Alpha is handled according to them, and the channels are reordered if they differ.
+   * Both are rgba by default.
+   * @param {PixelLayout} input
+   * @param {PixelLayout} [output=input]
+   */
+  setPixelLayout(input,output=input){
+    const [e,failed]=wasm.resizer_setPixelLayout(this.#ptr,...string(input),...string(output));
+    if(failed) throw error(e);
+  }
+  /**
+   * Resizes the next images in linear light instead of on the sRGB values,
+   * which keeps the brightness of high-contrast details such as text.
+   * @param {boolean} linearLight
+   */
+  setLinearLight(linearLight){
+    wasm.resizer_setLinearLight(this.#ptr,linearLight);
+  }
+  /**
+   * Restricts the next resizes to a sub-rectangle of the source image.
+   * Fractional values are allowed.
+   * @param {number} left
+   * @param {number} top
+   * @param {number} width
+   * @param {number} height
+   */
+  setCrop(left,top,width,height){
+    wasm.resizer_setCrop(this.#ptr,left,top,width,height);
+  }
+  /**
+   * Removes the crop rectangle, so that the next resizes use the whole source image.
+   */
+  clearCrop(){
+    wasm.resizer_clearCrop(this.#ptr);
+  }
+  /**
+   * Resizes the supplied ImageData rgba array.
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint8Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resize(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result(wasm.resizer_resize(this.#ptr,...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied ImageData rgba array, but computes only the rectangle of the result
+   * with the given position and dimensions, which is equal to the same rectangle of the whole result.
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @param {number} left
+   * @param {number} top
+   * @param {number} width
+   * @param {number} height
+   * @return {Uint8Array} the rgba array of the rectangle.
+   * @throws {Error} if the crop rectangle is outside of the source image or if the rectangle is outside of the result.
+   */
+  resizeRegion(data,sourceWidth,sourceHeight,targetWidth,targetHeight,left,top,width,height){
+    return result(wasm.resizer_resizeRegion(this.#ptr,...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight,left,top,width,height));
+  }
+  /**
+   * Resizes the supplied grayscale array (one byte per pixel).
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint8Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resizeGray(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result(wasm.resizer_resizeGray(this.#ptr,...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied grayscale array with alpha (two bytes per pixel, luma then alpha).
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint8Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resizeGrayAlpha(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result(wasm.resizer_resizeGrayAlpha(this.#ptr,...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied packed rgb array (three bytes per pixel).
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint8Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resizeRgb(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result(wasm.resizer_resizeRgb(this.#ptr,...copy(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied rgba array with 16 bits per channel.
+   * @param {Uint16Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint16Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resizeRgbaU16(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result16(wasm.resizer_resizeRgbaU16(this.#ptr,...copy16(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied RGB565 array (one u16 per pixel, red in the most significant bits).
+   * @param {Uint16Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint16Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resizeRgb565(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result16(wasm.resizer_resizeRgb565(this.#ptr,...copy16(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied RGBA4444 array (one u16 per pixel, red in the most significant bits).
+   * @param {Uint16Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint16Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resizeRgba4444(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result16(wasm.resizer_resizeRgba4444(this.#ptr,...copy16(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied grayscale array of floats (one per pixel); values are neither clamped nor rounded.
+   * @param {Float32Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Float32Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resizeGrayF32(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return resultF32(wasm.resizer_resizeGrayF32(this.#ptr,...copyF32(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied rgba array of floats (four per pixel); values are neither clamped nor rounded.
+   * @param {Float32Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Float32Array}
+   * @throws {Error} if the crop rectangle is outside of the source image.
+   */
+  resizeRgbaF32(data,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return resultF32(wasm.resizer_resizeRgbaF32(this.#ptr,...copyF32(data),sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied Y, U and V planes of an I420 image (e.g. copied from a VideoFrame),
+   * each with its own stride in bytes. Chroma planes have half the dimensions of the luma plane, rounded up.
+   * @param {Uint8Array} y
+   * @param {number} yStride
+   * @param {Uint8Array} u
+   * @param {number} uStride
+   * @param {Uint8Array} v
+   * @param {number} vStride
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint8Array} the resized Y, U and V planes one after another, without padding.
+   * @throws {Error} if a plane doesn't fit into its array with its stride
+   * or if the crop rectangle is outside of the source image.
+   */
+  resizeI420(y,yStride,u,uStride,v,vStride,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result(wasm.resizer_resizeI420(this.#ptr,...copy(y),yStride,...copy(u),uStride,...copy(v),vStride,sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied Y and interleaved UV planes of an NV12 image (e.g. copied from a VideoFrame),
+   * each with its own stride in bytes. The chroma plane has half the dimensions of the luma plane, rounded up.
+   * @param {Uint8Array} y
+   * @param {number} yStride
+   * @param {Uint8Array} uv
+   * @param {number} uvStride
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint8Array} the resized Y and UV planes one after another, without padding.
+   * @throws {Error} if a plane doesn't fit into its array with its stride,
+   * if the stride of the UV plane is odd or if the crop rectangle is outside of the source image.
+   */
+  resizeNv12(y,yStride,uv,uvStride,sourceWidth,sourceHeight,targetWidth,targetHeight){
+    return result(wasm.resizer_resizeNv12(this.#ptr,...copy(y),yStride,...copy(uv),uvStride,sourceWidth,sourceHeight,targetWidth,targetHeight));
+  }
+  /**
+   * Resizes the supplied ImageData rgba array into the supplied target array.
+   * @param {Uint8Array} data
+   * @param {number} sourceWidth
+   * @param {number} sourceHeight
+   * @param {Uint8Array} target
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   */
+  resizeInto(data,sourceWidth,sourceHeight,target,targetWidth,targetHeight){
+    const [e,failed]=wasm.resizer_resizeInto(this.#ptr,...copy(data),sourceWidth,sourceHeight,...copy(target),target,targetWidth,targetHeight);
+    if(failed) throw error(e);
+  }
+  /**
+   * Returns a view on a region of wasm memory, kept by this resizer, for a source
+   * rgba image of the given dimensions. The pixels should be written directly into it.
+   * @param {number} width
+   * @param {number} height
+   * @return {Uint8Array}
+   */
+  sourceBuffer(width,height){
+    const [p,e,failed]=wasm.resizer_sourceBuffer(this.#ptr,width,height);
+    if(failed) throw error(e);
+    this.#source=[p>>>0,width*height*4];
+    return this.source;
+  }
+  /**
+   * Resizes the image written in the source buffer, without any copy, and returns
+   * a view on the result, which is also kept in wasm memory by this resizer.
+   * @param {number} targetWidth
+   * @param {number} targetHeight
+   * @return {Uint8Array}
+   * @throws {Error} if there's no source buffer.
+   */
+  resizeSourceBuffer(targetWidth,targetHeight){
+    const [p,e,failed]=wasm.resizer_resizeSourceBuffer(this.#ptr,targetWidth,targetHeight);
+    if(failed) throw error(e);
+    this.#destination=[p>>>0,targetWidth*targetHeight*4];
+    return this.destination;
+  }
+  /**
+   * View on the source buffer, or null before the first call to sourceBuffer().
+   * A view is detached when the wasm memory grows, which any call to the module
+   * may do, so a new one is created from the current memory on each access.
+   * @type {Uint8Array|null}
+   */
+  get source(){
+    return this.#source&&new Uint8Array(wasm.memory.buffer,...this.#source);
+  }
+  /**
+   * View on the result of the last call to resizeSourceBuffer(), or null before it.
+   * It is created again on each access, like the source view.
+   * @type {Uint8Array|null}
+   */
+  get destination(){
+    return this.#destination&&new Uint8Array(wasm.memory.buffer,...this.#destination);
+  }
+  /**
+   * Releases the wasm memory used by this resizer.
+   */
+  free(){
+    const p=this.#ptr;
+    this.#ptr=0;
+    this.#source=this.#destination=null;
+    registry.unregister(this);
+    wasm.__wbg_resizer_free(p,0);
+  }
+}
+/**
+ * Returns the name of the CPU extensions used by the loaded module:
+ * simd128, or none for the scalar build loaded by engines without SIMD support.
+ * It only reports the extensions, which can't be selected.
+ * @return {CpuExtensions}
+ */
+const cpuExtensions=()=>{
+  const [p,n]=wasm.cpuExtensions();
+  const res=text(p,n);
+  free(p,n,1);
   return res;
 };
-export {resize};
+export {cpuExtensions,resize,resizeInto,resizeRegion,resizeGray,resizeGrayAlpha,resizeRgb,resizeRgbaU16,resizeRgb565,resizeRgba4444,resizeGrayF32,resizeRgbaF32,resizeI420,resizeNv12,multiplyAlpha,divideAlpha,Resizer};
 export default resize;
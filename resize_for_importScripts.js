@@ -1,12 +1,16 @@
+importScripts('./resize_wasm_file.js');
 const resize=(async()=>{
   let wasm;
+  const text=(p,n)=>new TextDecoder().decode(new Uint8Array(wasm.memory.buffer).subarray(p,p+n));
   const imports={
-    wbg:{
-      __wbg_log_cb485ef4b8fcc41d:(p,n)=>{
-        console.log(new TextDecoder().decode(new Uint8Array(wasm.memory.buffer).subarray(p,p+n)));
+    './wasm_resize_rgba_bg.js':{
+      __wbg_Error_30c8987f7c2ed4e2:(p,n)=>Error(text(p,n)),
+      __wbg___wbindgen_copy_to_typed_array_88899a52af046901:(p,n,a)=>{
+        new Uint8Array(a.buffer,a.byteOffset,a.byteLength).set(new Uint8Array(wasm.memory.buffer).subarray(p,p+n));
       },
+      __wbg___wbindgen_throw_41e9ee4f547fc59a:(p,n)=>{throw new Error(text(p,n))},
       __wbindgen_init_externref_table:function(){
-        const table=wasm.__wbindgen_export_0;
+        const table=wasm.__wbindgen_externrefs;
         const offset=table.grow(4);
         table.set(0);
         table.set(offset);
@@ -16,17 +20,28 @@ const resize=(async()=>{
       }
     }
   };
-  const {instance}=await WebAssembly.instantiateStreaming(await fetch('./resize.wasm',{cache: 'force-cache'}),imports);
+  const {instance}=await WebAssembly.instantiateStreaming(await fetch('./'+resizeWasmFile,{cache: 'force-cache'}),imports);
   wasm=instance.exports;
+  wasm.__wbindgen_start();
   const malloc=wasm.__wbindgen_malloc;
   const free=wasm.__wbindgen_free;
-  return (data,sourceWidth,sourceHeight,targetWidth,targetHeight,hq=true)=>{
+  const error=i=>{
+    const e=wasm.__wbindgen_externrefs.get(i);
+    wasm.__externref_table_dealloc(i);
+    return e;
+  };
+  return (data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom',linearLight=false)=>{
     const n1=data.length;
     const p1=malloc(n1,1);
     new Uint8Array(wasm.memory.buffer).set(data,p1);
-    const [p2,n2]=wasm.resize(p1,n1,sourceWidth,sourceHeight,targetWidth,targetHeight,hq);
+    const name=new TextEncoder().encode(algorithm===true?'catmullrom':algorithm===false?'hamming':algorithm);
+    const n3=name.length;
+    const p3=malloc(n3,1);
+    new Uint8Array(wasm.memory.buffer).set(name,p3);
+    const [p2,n2,e,failed]=wasm.resize(p1,n1,sourceWidth,sourceHeight,targetWidth,targetHeight,p3,n3,linearLight);
+    if(failed) throw error(e);
     const res=new Uint8Array(wasm.memory.buffer).subarray(p2,p2+n2).slice();
-    free(p2,n2);
+    free(p2,n2,1);
     return res;
   };
 })();
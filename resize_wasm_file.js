@@ -0,0 +1,6 @@
+// Engines without SIMD support (e.g. older Safari versions) can't compile resize.wasm,
+// so they load the scalar build instead. The probe is a tiny module with a SIMD instruction.
+// This script is shared by the modules, which import it, and by importScripts.
+globalThis.resizeWasmFile=WebAssembly.validate(
+  new Uint8Array([0,97,115,109,1,0,0,0,1,5,1,96,0,1,123,3,2,1,0,10,10,1,8,0,65,0,253,15,253,98,11])
+)?'resize.wasm':'resize_scalar.wasm';
@@ -1,6 +1,7 @@
 /* tslint:disable */
 /* eslint-disable */
 declare module 'resize_worker' {
+  import type {Algorithm} from 'resize';
   /**
    * Resizes the supplied ImageData rgba array.
    * @param {Uint8Array} data
@@ -8,14 +9,17 @@ declare module 'resize_worker' {
    * @param {number} sourceHeight
    * @param {number} targetWidth
    * @param {number} targetHeight
-   * @param {boolean} [hq=true]
-   * @return {Promise<Uint8Array>}
+   * @param {Algorithm|boolean} [algorithm='catmullrom']
+   * @param {boolean} [linearLight=false] resizes in linear light instead of on the sRGB values.
+   * @return {Promise<Uint8Array>} rejected if a dimension is zero, if the data length doesn't match the source dimensions
+   * or if the algorithm is invalid.
    */
   export function resize(
     data: Uint8Array,
     sourceWidth: number, sourceHeight: number,
     targetWidth: number, targetHeight: number,
-    hq: boolean
+    algorithm?: Algorithm|boolean,
+    linearLight?: boolean
   ): Promise<Uint8Array>;
   export default resize;
 }
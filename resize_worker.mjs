@@ -1,4 +1,5 @@
-const url=new URL('resize.wasm',import.meta.url);
+import './resize_wasm_file.js';
+const url=new URL(globalThis.resizeWasmFile,import.meta.url);
 await (await fetch(url)).arrayBuffer();
 const worker=await new Promise(r=>{
   // For browsers that don't support type: module on workers (firefox < 114, safari < 15)
@@ -18,15 +19,17 @@ const worker=await new Promise(r=>{
  * @param {number} sourceHeight
  * @param {number} targetWidth
  * @param {number} targetHeight
- * @param {boolean} [hq=true]
+ * @param {import('./resize.mjs').Algorithm} [algorithm='catmullrom']
+ * @param {boolean} [linearLight=false] resizes in linear light instead of on the sRGB values.
  * @return {Promise<Uint8Array>}
  */
-const resize=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,hq=true)=>new Promise(r=>{
+const resize=(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm='catmullrom',linearLight=false)=>new Promise((r,j)=>{
   worker.onmessage=msg=>{
     worker.onmessage=null;
-    r(msg.data);
+    if(msg.data instanceof Error) j(msg.data);
+    else r(msg.data);
   }
-  worker.postMessage({data,sourceWidth,sourceHeight,targetWidth,targetHeight,hq});
+  worker.postMessage({data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm,linearLight});
 });
 
 export {resize};
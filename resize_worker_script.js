@@ -2,7 +2,11 @@ importScripts('./resize_for_importScripts.js');
 (async()=>{
   const fn=await resize;
   onmessage=async msg=>{
-    postMessage(fn(msg.data.data,msg.data.sourceWidth,msg.data.sourceHeight,msg.data.targetWidth,msg.data.targetHeight,msg.data.hq));
+    try{
+      postMessage(fn(msg.data.data,msg.data.sourceWidth,msg.data.sourceHeight,msg.data.targetWidth,msg.data.targetHeight,msg.data.algorithm,msg.data.linearLight));
+    }catch(e){
+      postMessage(e);
+    }
   }
   postMessage('ready');
 })();
@@ -1,3 +1,9 @@
 import {resize} from "./resize.mjs";
-onmessage=async({data:{data,sourceWidth,sourceHeight,targetWidth,targetHeight,hq}})=>postMessage(resize(data,sourceWidth,sourceHeight,targetWidth,targetHeight,hq));
+onmessage=({data:{data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm,linearLight}})=>{
+  try{
+    postMessage(resize(data,sourceWidth,sourceHeight,targetWidth,targetHeight,algorithm,linearLight));
+  }catch(e){
+    postMessage(e);
+  }
+};
 postMessage('ready');
@@ -4,12 +4,9 @@ pub(crate) fn mul_div_255(a: u8, b: u8) -> u8 {
     (((tmp >> 8) + tmp) >> 8) as u8
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 const PRECISION: u32 = 8;
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) const RECIP_ALPHA: [u32; 256] = recip_alpha_array(PRECISION);
 
-#[cfg(not(target_arch = "wasm32"))]
 const fn recip_alpha_array(precision: u32) -> [u32; 256] {
     let mut res = [0; 256];
     let scale = 1 << (precision + 1);
@@ -22,8 +19,22 @@ const fn recip_alpha_array(precision: u32) -> [u32; 256] {
     res
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[inline(always)]
 pub(crate) fn div_and_clip(v: u8, recip_alpha: u32) -> u8 {
     ((v as u32 * recip_alpha) >> PRECISION).min(255) as u8
 }
+
+#[inline(always)]
+pub(crate) fn mul_div_65535(a: u16, b: u16) -> u16 {
+    let tmp = a as u32 * b as u32 + 0x8000;
+    (((tmp >> 16) + tmp) >> 16) as u16
+}
+
+#[inline(always)]
+pub(crate) fn div_and_clip16(v: u16, alpha: u16) -> u16 {
+    if alpha == 0 {
+        return 0;
+    }
+    let alpha = alpha as u32;
+    ((v as u32 * 0xffff + alpha / 2) / alpha).min(0xffff) as u16
+}
@@ -0,0 +1,71 @@
+use crate::image_view::{ImageView, ImageViewMut};
+use crate::pixels::F32x4;
+
+pub(crate) fn multiply_alpha(src_image: &ImageView<F32x4>, dst_image: &mut ImageViewMut<F32x4>) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = multiply_alpha_pixel(*src_pixel);
+        }
+    }
+}
+
+pub(crate) fn multiply_alpha_inplace(image: &mut ImageViewMut<F32x4>) {
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = multiply_alpha_pixel(*pixel);
+        }
+    }
+}
+
+#[inline(always)]
+fn multiply_alpha_pixel(mut pixel: F32x4) -> F32x4 {
+    let alpha = pixel.0[3];
+    pixel.0 = [
+        pixel.0[0] * alpha,
+        pixel.0[1] * alpha,
+        pixel.0[2] * alpha,
+        alpha,
+    ];
+    pixel
+}
+
+pub(crate) fn divide_alpha(src_image: &ImageView<F32x4>, dst_image: &mut ImageViewMut<F32x4>) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = divide_alpha_pixel(*src_pixel);
+        }
+    }
+}
+
+pub(crate) fn divide_alpha_inplace(image: &mut ImageViewMut<F32x4>) {
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = divide_alpha_pixel(*pixel);
+        }
+    }
+}
+
+#[inline(always)]
+fn divide_alpha_pixel(mut pixel: F32x4) -> F32x4 {
+    let alpha = pixel.0[3];
+    // Values aren't clamped, so alpha isn't either; only the fully
+    // transparent pixels lose their color.
+    if alpha == 0. {
+        pixel.0 = [0.; 4];
+    } else {
+        let recip_alpha = 1. / alpha;
+        pixel.0 = [
+            pixel.0[0] * recip_alpha,
+            pixel.0[1] * recip_alpha,
+            pixel.0[2] * recip_alpha,
+            alpha,
+        ];
+    }
+    pixel
+}
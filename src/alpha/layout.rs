@@ -0,0 +1,111 @@
+//! Scalar alpha passes for `U8x4` pixels with alpha channel at any position.
+//!
+//! The SIMD kernels of `U8x4` expect alpha channel to be the last component,
+//! so these functions are used for other layouts and for the output passes
+//! that also reorder the components.
+use crate::alpha::common::{div_and_clip, mul_div_255, RECIP_ALPHA};
+use crate::pixels::{PixelLayout, U8x4};
+use crate::{ImageView, ImageViewMut};
+
+pub(crate) fn multiply_alpha(
+    src_image: &ImageView<U8x4>,
+    dst_image: &mut ImageViewMut<U8x4>,
+    layout: PixelLayout,
+) {
+    let alpha_index = layout.alpha_index();
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = multiply_alpha_pixel(*src_pixel, alpha_index);
+        }
+    }
+}
+
+pub(crate) fn multiply_alpha_inplace(image: &mut ImageViewMut<U8x4>, layout: PixelLayout) {
+    let alpha_index = layout.alpha_index();
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = multiply_alpha_pixel(*pixel, alpha_index);
+        }
+    }
+}
+
+/// Divides the color components of source image by alpha channel and stores
+/// them into destination image, reordered from `from` layout to `to` layout.
+pub(crate) fn divide_alpha(
+    src_image: &ImageView<U8x4>,
+    dst_image: &mut ImageViewMut<U8x4>,
+    from: PixelLayout,
+    to: PixelLayout,
+) {
+    let alpha_index = from.alpha_index();
+    let swizzle = from.swizzle(to);
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = swizzle_pixel(divide_alpha_pixel(*src_pixel, alpha_index), swizzle);
+        }
+    }
+}
+
+/// Divides the color components of image by alpha channel inplace
+/// and reorders them from `from` layout to `to` layout.
+pub(crate) fn divide_alpha_inplace(
+    image: &mut ImageViewMut<U8x4>,
+    from: PixelLayout,
+    to: PixelLayout,
+) {
+    let alpha_index = from.alpha_index();
+    let swizzle = from.swizzle(to);
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = swizzle_pixel(divide_alpha_pixel(*pixel, alpha_index), swizzle);
+        }
+    }
+}
+
+/// Reorders the components of image inplace from `from` layout to `to` layout.
+pub(crate) fn swizzle_inplace(image: &mut ImageViewMut<U8x4>, from: PixelLayout, to: PixelLayout) {
+    if from == to {
+        return;
+    }
+    let swizzle = from.swizzle(to);
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = swizzle_pixel(*pixel, swizzle);
+        }
+    }
+}
+
+#[inline(always)]
+fn multiply_alpha_pixel(mut pixel: U8x4, alpha_index: usize) -> U8x4 {
+    let alpha = pixel.0[alpha_index];
+    for (i, component) in pixel.0.iter_mut().enumerate() {
+        if i != alpha_index {
+            *component = mul_div_255(*component, alpha);
+        }
+    }
+    pixel
+}
+
+#[inline(always)]
+fn divide_alpha_pixel(mut pixel: U8x4, alpha_index: usize) -> U8x4 {
+    let recip_alpha = RECIP_ALPHA[pixel.0[alpha_index] as usize];
+    for (i, component) in pixel.0.iter_mut().enumerate() {
+        if i != alpha_index {
+            *component = div_and_clip(*component, recip_alpha);
+        }
+    }
+    pixel
+}
+
+#[inline(always)]
+fn swizzle_pixel(mut pixel: U8x4, swizzle: [usize; 4]) -> U8x4 {
+    let components = pixel.0;
+    pixel.0 = swizzle.map(|i| components[i]);
+    pixel
+}
@@ -3,6 +3,11 @@ use crate::CpuExtensions;
 use crate::{ImageView, ImageViewMut};
 
 mod common;
+mod f32x4;
+pub(crate) mod layout;
+mod rgba4444;
+mod u16x4;
+mod u8x2;
 mod u8x4;
 
 pub(crate) trait AlphaMulDiv
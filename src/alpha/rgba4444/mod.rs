@@ -0,0 +1,33 @@
+use crate::pixels::Rgba4444;
+use crate::CpuExtensions;
+use crate::{ImageView, ImageViewMut};
+
+use super::AlphaMulDiv;
+
+mod native;
+
+impl AlphaMulDiv for Rgba4444 {
+    fn multiply_alpha(
+        src_image: &ImageView<Self>,
+        dst_image: &mut ImageViewMut<Self>,
+        _cpu_extensions: CpuExtensions,
+    ) {
+        native::multiply_alpha(src_image, dst_image);
+    }
+
+    fn multiply_alpha_inplace(image: &mut ImageViewMut<Self>, _cpu_extensions: CpuExtensions) {
+        native::multiply_alpha_inplace(image);
+    }
+
+    fn divide_alpha(
+        src_image: &ImageView<Self>,
+        dst_image: &mut ImageViewMut<Self>,
+        _cpu_extensions: CpuExtensions,
+    ) {
+        native::divide_alpha(src_image, dst_image);
+    }
+
+    fn divide_alpha_inplace(image: &mut ImageViewMut<Self>, _cpu_extensions: CpuExtensions) {
+        native::divide_alpha_inplace(image);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::alpha::common::{div_and_clip, mul_div_255, RECIP_ALPHA};
+use crate::image_view::{ImageView, ImageViewMut};
+use crate::packed::{PackedPixel, ROUNDING_THRESHOLD};
+use crate::pixels::Rgba4444;
+
+pub(crate) fn multiply_alpha(src_image: &ImageView<Rgba4444>, dst_image: &mut ImageViewMut<Rgba4444>) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = multiply_alpha_pixel(*src_pixel);
+        }
+    }
+}
+
+pub(crate) fn multiply_alpha_inplace(image: &mut ImageViewMut<Rgba4444>) {
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = multiply_alpha_pixel(*pixel);
+        }
+    }
+}
+
+#[inline(always)]
+fn multiply_alpha_pixel(pixel: Rgba4444) -> Rgba4444 {
+    let mut unpacked = pixel.unpack();
+    let [r, g, b, alpha] = unpacked.0;
+    unpacked.0 = [
+        mul_div_255(r, alpha),
+        mul_div_255(g, alpha),
+        mul_div_255(b, alpha),
+        alpha,
+    ];
+    Rgba4444::pack(unpacked, ROUNDING_THRESHOLD)
+}
+
+pub(crate) fn divide_alpha(src_image: &ImageView<Rgba4444>, dst_image: &mut ImageViewMut<Rgba4444>) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = divide_alpha_pixel(*src_pixel);
+        }
+    }
+}
+
+pub(crate) fn divide_alpha_inplace(image: &mut ImageViewMut<Rgba4444>) {
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = divide_alpha_pixel(*pixel);
+        }
+    }
+}
+
+#[inline(always)]
+fn divide_alpha_pixel(pixel: Rgba4444) -> Rgba4444 {
+    let mut unpacked = pixel.unpack();
+    let [r, g, b, alpha] = unpacked.0;
+    let recip_alpha = RECIP_ALPHA[alpha as usize];
+    unpacked.0 = [
+        div_and_clip(r, recip_alpha),
+        div_and_clip(g, recip_alpha),
+        div_and_clip(b, recip_alpha),
+        alpha,
+    ];
+    Rgba4444::pack(unpacked, ROUNDING_THRESHOLD)
+}
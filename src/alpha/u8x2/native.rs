@@ -0,0 +1,56 @@
+use crate::alpha::common::{div_and_clip, mul_div_255, RECIP_ALPHA};
+use crate::image_view::{ImageView, ImageViewMut};
+use crate::pixels::U8x2;
+
+pub(crate) fn multiply_alpha(src_image: &ImageView<U8x2>, dst_image: &mut ImageViewMut<U8x2>) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = multiply_alpha_pixel(*src_pixel);
+        }
+    }
+}
+
+pub(crate) fn multiply_alpha_inplace(image: &mut ImageViewMut<U8x2>) {
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = multiply_alpha_pixel(*pixel);
+        }
+    }
+}
+
+#[inline(always)]
+fn multiply_alpha_pixel(mut pixel: U8x2) -> U8x2 {
+    let alpha = pixel.0[1];
+    pixel.0 = [mul_div_255(pixel.0[0], alpha), alpha];
+    pixel
+}
+
+pub(crate) fn divide_alpha(src_image: &ImageView<U8x2>, dst_image: &mut ImageViewMut<U8x2>) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = divide_alpha_pixel(*src_pixel);
+        }
+    }
+}
+
+pub(crate) fn divide_alpha_inplace(image: &mut ImageViewMut<U8x2>) {
+    for row in image.iter_rows_mut() {
+        for pixel in row.iter_mut() {
+            *pixel = divide_alpha_pixel(*pixel);
+        }
+    }
+}
+
+#[inline(always)]
+fn divide_alpha_pixel(mut pixel: U8x2) -> U8x2 {
+    let alpha = pixel.0[1];
+    let recip_alpha = RECIP_ALPHA[alpha as usize];
+    pixel.0 = [div_and_clip(pixel.0[0], recip_alpha), alpha];
+    pixel
+}
@@ -6,7 +6,9 @@ use super::AlphaMulDiv;
 
 
 mod native;
-#[cfg(target_arch = "wasm32")]
+#[cfg(target_arch = "x86_64")]
+mod sse4;
+#[cfg(target_feature = "simd128")]
 mod wasm32;
 
 impl AlphaMulDiv for U8x4 {
@@ -16,18 +18,24 @@ impl AlphaMulDiv for U8x4 {
         cpu_extensions: CpuExtensions,
     ) {
         match cpu_extensions {
-            #[cfg(target_arch = "wasm32")]
+            #[cfg(target_feature = "simd128")]
             CpuExtensions::Simd128 => unsafe { wasm32::multiply_alpha(src_image, dst_image) },
-            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Avx2 | CpuExtensions::Sse4_1 => unsafe {
+                sse4::multiply_alpha(src_image, dst_image)
+            },
             CpuExtensions::None => native::multiply_alpha(src_image, dst_image),
         }
     }
 
     fn multiply_alpha_inplace(image: &mut ImageViewMut<Self>, cpu_extensions: CpuExtensions) {
         match cpu_extensions {
-            #[cfg(target_arch = "wasm32")]
+            #[cfg(target_feature = "simd128")]
             CpuExtensions::Simd128 => unsafe { wasm32::multiply_alpha_inplace(image) },
-            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Avx2 | CpuExtensions::Sse4_1 => unsafe {
+                sse4::multiply_alpha_inplace(image)
+            },
             CpuExtensions::None => native::multiply_alpha_inplace(image),
         }
     }
@@ -38,18 +46,24 @@ impl AlphaMulDiv for U8x4 {
         cpu_extensions: CpuExtensions,
     ) {
         match cpu_extensions {
-            #[cfg(target_arch = "wasm32")]
+            #[cfg(target_feature = "simd128")]
             CpuExtensions::Simd128 => unsafe { wasm32::divide_alpha(src_image, dst_image) },
-            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Avx2 | CpuExtensions::Sse4_1 => unsafe {
+                sse4::divide_alpha(src_image, dst_image)
+            },
             CpuExtensions::None => native::divide_alpha(src_image, dst_image),
         }
     }
 
     fn divide_alpha_inplace(image: &mut ImageViewMut<Self>, cpu_extensions: CpuExtensions) {
         match cpu_extensions {
-            #[cfg(target_arch = "wasm32")]
+            #[cfg(target_feature = "simd128")]
             CpuExtensions::Simd128 => unsafe { wasm32::divide_alpha_inplace(image) },
-            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Avx2 | CpuExtensions::Sse4_1 => unsafe {
+                sse4::divide_alpha_inplace(image)
+            },
             CpuExtensions::None => native::divide_alpha_inplace(image),
         }
     }
@@ -1,11 +1,8 @@
 use crate::alpha::common::mul_div_255;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::alpha::common::{RECIP_ALPHA,div_and_clip};
 use crate::pixels::U8x4;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::image_view::{ImageView, ImageViewMut};
 
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) fn multiply_alpha(src_image: &ImageView<U8x4>, dst_image: &mut ImageViewMut<U8x4>) {
     let src_rows = src_image.iter_rows(0);
     let dst_rows = dst_image.iter_rows_mut();
@@ -17,14 +14,13 @@ pub(crate) fn multiply_alpha(src_image: &ImageView<U8x4>, dst_image: &mut ImageV
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 pub(crate) fn multiply_alpha_inplace(image: &mut ImageViewMut<U8x4>) {
     for row in image.iter_rows_mut() {
         multiply_alpha_row_inplace(row);
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(any(target_feature = "simd128", target_arch = "x86_64"))]
 #[inline(always)]
 pub(crate) fn multiply_alpha_row(src_row: &[U8x4], dst_row: &mut [U8x4]) {
     for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row) {
@@ -51,7 +47,6 @@ fn multiply_alpha_pixel(mut pixel: U8x4) -> U8x4 {
     pixel
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[inline]
 pub(crate) fn divide_alpha(src_image: &ImageView<U8x4>, dst_image: &mut ImageViewMut<U8x4>) {
     let src_rows = src_image.iter_rows(0);
@@ -62,17 +57,13 @@ pub(crate) fn divide_alpha(src_image: &ImageView<U8x4>, dst_image: &mut ImageVie
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[inline]
 pub(crate) fn divide_alpha_inplace(image: &mut ImageViewMut<U8x4>) {
     for row in image.iter_rows_mut() {
-        row.iter_mut().for_each(|pixel| {
-            *pixel = divide_alpha_pixel(*pixel);
-        });
+        divide_alpha_row_inplace(row);
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[inline(always)]
 pub(crate) fn divide_alpha_row(src_row: &[U8x4], dst_row: &mut [U8x4]) {
     for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row) {
@@ -80,7 +71,13 @@ pub(crate) fn divide_alpha_row(src_row: &[U8x4], dst_row: &mut [U8x4]) {
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+#[inline(always)]
+pub(crate) fn divide_alpha_row_inplace(row: &mut [U8x4]) {
+    row.iter_mut().for_each(|pixel| {
+        *pixel = divide_alpha_pixel(*pixel);
+    });
+}
+
 #[inline(always)]
 fn divide_alpha_pixel(mut pixel: U8x4) -> U8x4 {
     let alpha = pixel.0[3];
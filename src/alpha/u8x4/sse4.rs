@@ -0,0 +1,197 @@
+use std::arch::x86_64::*;
+
+use crate::alpha::common::RECIP_ALPHA;
+use crate::pixels::U8x4;
+use crate::x86_64_utils;
+use crate::{ImageView, ImageViewMut};
+
+use super::native;
+
+pub(crate) unsafe fn multiply_alpha(
+    src_image: &ImageView<U8x4>,
+    dst_image: &mut ImageViewMut<U8x4>,
+) {
+    unsafe {
+        let src_rows = src_image.iter_rows(0);
+        let dst_rows = dst_image.iter_rows_mut();
+
+        for (src_row, dst_row) in src_rows.zip(dst_rows) {
+            multiply_alpha_row(src_row, dst_row);
+        }
+    }
+}
+
+pub(crate) unsafe fn multiply_alpha_inplace(image: &mut ImageViewMut<U8x4>) {
+    unsafe {
+        for row in image.iter_rows_mut() {
+            multiply_alpha_row_inplace(row);
+        }
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn multiply_alpha_row(src_row: &[U8x4], dst_row: &mut [U8x4]) {
+    unsafe {
+        let src_chunks = src_row.chunks_exact(4);
+        let src_remainder = src_chunks.remainder();
+        let mut dst_chunks = dst_row.chunks_exact_mut(4);
+        let src_dst = src_chunks.zip(&mut dst_chunks);
+        for (src, dst) in src_dst {
+            let mut pixels = x86_64_utils::loadu_si128(src, 0);
+            pixels = multiply_alpha_4_pixels(pixels);
+            _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, pixels);
+        }
+
+        if !src_remainder.is_empty() {
+            let dst_reminder = dst_chunks.into_remainder();
+            native::multiply_alpha_row(src_remainder, dst_reminder);
+        }
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn multiply_alpha_row_inplace(row: &mut [U8x4]) {
+    unsafe {
+        let mut chunks = row.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let mut pixels = x86_64_utils::loadu_si128(chunk, 0);
+            pixels = multiply_alpha_4_pixels(pixels);
+            _mm_storeu_si128(chunk.as_mut_ptr() as *mut __m128i, pixels);
+        }
+
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            native::multiply_alpha_row_inplace(tail);
+        }
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn multiply_alpha_4_pixels(pixels: __m128i) -> __m128i {
+    let zero = _mm_setzero_si128();
+    let half = _mm_set1_epi16(128);
+    let max_alpha = _mm_set1_epi32(0xff000000u32 as i32);
+    let factor_mask = _mm_set_epi8(15, 15, 15, 15, 11, 11, 11, 11, 7, 7, 7, 7, 3, 3, 3, 3);
+
+    // Alpha is multiplied by 255, so it stays unchanged.
+    let factor_pixels = _mm_shuffle_epi8(pixels, factor_mask);
+    let factor_pixels = _mm_or_si128(factor_pixels, max_alpha);
+
+    let src_u16_lo = _mm_cvtepu8_epi16(pixels);
+    let factors = _mm_cvtepu8_epi16(factor_pixels);
+    let mut dst_u16_lo = _mm_add_epi16(_mm_mullo_epi16(src_u16_lo, factors), half);
+    dst_u16_lo = _mm_add_epi16(dst_u16_lo, _mm_srli_epi16::<8>(dst_u16_lo));
+    dst_u16_lo = _mm_srli_epi16::<8>(dst_u16_lo);
+
+    let src_u16_hi = _mm_unpackhi_epi8(pixels, zero);
+    let factors = _mm_unpackhi_epi8(factor_pixels, zero);
+    let mut dst_u16_hi = _mm_add_epi16(_mm_mullo_epi16(src_u16_hi, factors), half);
+    dst_u16_hi = _mm_add_epi16(dst_u16_hi, _mm_srli_epi16::<8>(dst_u16_hi));
+    dst_u16_hi = _mm_srli_epi16::<8>(dst_u16_hi);
+
+    _mm_packus_epi16(dst_u16_lo, dst_u16_hi)
+}
+
+// Divide
+
+pub(crate) unsafe fn divide_alpha(src_image: &ImageView<U8x4>, dst_image: &mut ImageViewMut<U8x4>) {
+    unsafe {
+        let src_rows = src_image.iter_rows(0);
+        let dst_rows = dst_image.iter_rows_mut();
+        for (src_row, dst_row) in src_rows.zip(dst_rows) {
+            divide_alpha_row(src_row, dst_row);
+        }
+    }
+}
+
+pub(crate) unsafe fn divide_alpha_inplace(image: &mut ImageViewMut<U8x4>) {
+    unsafe {
+        for row in image.iter_rows_mut() {
+            divide_alpha_row_inplace(row);
+        }
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn divide_alpha_row(src_row: &[U8x4], dst_row: &mut [U8x4]) {
+    unsafe {
+        let src_chunks = src_row.chunks_exact(4);
+        let src_remainder = src_chunks.remainder();
+        let mut dst_chunks = dst_row.chunks_exact_mut(4);
+        let src_dst = src_chunks.zip(&mut dst_chunks);
+        for (src, dst) in src_dst {
+            let mut pixels = x86_64_utils::loadu_si128(src, 0);
+            pixels = divide_alpha_4_pixels(pixels);
+            _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, pixels);
+        }
+
+        if !src_remainder.is_empty() {
+            let dst_reminder = dst_chunks.into_remainder();
+            native::divide_alpha_row(src_remainder, dst_reminder);
+        }
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn divide_alpha_row_inplace(row: &mut [U8x4]) {
+    unsafe {
+        let mut chunks = row.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let mut pixels = x86_64_utils::loadu_si128(chunk, 0);
+            pixels = divide_alpha_4_pixels(pixels);
+            _mm_storeu_si128(chunk.as_mut_ptr() as *mut __m128i, pixels);
+        }
+
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            native::divide_alpha_row_inplace(tail);
+        }
+    }
+}
+
+/// Uses the same table of reciprocals as the scalar code,
+/// so the results are equal to the results of `native::divide_alpha_row`.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn divide_alpha_4_pixels(pixels: __m128i) -> __m128i {
+    unsafe {
+        let zero = _mm_setzero_si128();
+        let alpha_mask = _mm_set1_epi32(0xff000000u32 as i32);
+        let max_value = _mm_set1_epi16(255);
+        let factor_lo_shuffle = _mm_set_epi8(-1, -1, 5, 4, 5, 4, 5, 4, -1, -1, 1, 0, 1, 0, 1, 0);
+        let factor_hi_shuffle = _mm_set_epi8(-1, -1, 13, 12, 13, 12, 13, 12, -1, -1, 9, 8, 9, 8, 9, 8);
+
+        // Reciprocals are less than 2^16, so they fit into lower halves of the lanes.
+        let recip_alpha = _mm_set_epi32(
+            RECIP_ALPHA[_mm_extract_epi8::<15>(pixels) as usize] as i32,
+            RECIP_ALPHA[_mm_extract_epi8::<11>(pixels) as usize] as i32,
+            RECIP_ALPHA[_mm_extract_epi8::<7>(pixels) as usize] as i32,
+            RECIP_ALPHA[_mm_extract_epi8::<3>(pixels) as usize] as i32,
+        );
+        let factor_lo = _mm_shuffle_epi8(recip_alpha, factor_lo_shuffle);
+        let factor_hi = _mm_shuffle_epi8(recip_alpha, factor_hi_shuffle);
+
+        let src_u16_lo = _mm_cvtepu8_epi16(pixels);
+        let src_u16_hi = _mm_unpackhi_epi8(pixels, zero);
+        let dst_lo = _mm_min_epu16(mul_shr8_epu16(src_u16_lo, factor_lo), max_value);
+        let dst_hi = _mm_min_epu16(mul_shr8_epu16(src_u16_hi, factor_hi), max_value);
+
+        let alpha = _mm_and_si128(pixels, alpha_mask);
+        let rgb = _mm_packus_epi16(dst_lo, dst_hi);
+        _mm_or_si128(rgb, alpha)
+    }
+}
+
+/// Returns bits 8..24 of the 32-bit products of unsigned 16-bit integers.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn mul_shr8_epu16(a: __m128i, b: __m128i) -> __m128i {
+    let lo = _mm_mullo_epi16(a, b);
+    let hi = _mm_mulhi_epu16(a, b);
+    _mm_or_si128(_mm_srli_epi16::<8>(lo), _mm_slli_epi16::<8>(hi))
+}
@@ -0,0 +1,52 @@
+use std::sync::OnceLock;
+
+use super::PixelComponentMapper;
+
+fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Creates a mapper from sRGB colorspace into linear RGB and back.
+pub fn create_srgb_mapper() -> PixelComponentMapper {
+    PixelComponentMapper::new(srgb_to_linear, linear_to_srgb)
+}
+
+/// Returns the sRGB mapper shared by all resizers, so that its tables
+/// are computed only once.
+pub(crate) fn srgb_mapper() -> &'static PixelComponentMapper {
+    static MAPPER: OnceLock<PixelComponentMapper> = OnceLock::new();
+    MAPPER.get_or_init(create_srgb_mapper)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+    use crate::{Image, MappingError, PixelType};
+
+    use super::create_srgb_mapper;
+
+    #[test]
+    fn test_image_types() {
+        let mapper = create_srgb_mapper();
+        let src = Image::new(2, 1, PixelType::U16x4);
+        let mut dst = Image::new(2, 1, PixelType::U8x4);
+        assert_eq!(
+            mapper.forward_map(&src.view(), &mut dst.view_mut()),
+            Err(MappingError::UnsupportedCombinationOfImageTypes)
+        );
+        mapper.backward_map(&src.view(), &mut dst.view_mut()).unwrap();
+    }
+}
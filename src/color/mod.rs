@@ -1,11 +1,12 @@
 //! Functions and structs for working with colorspace and gamma.
 use num_traits::bounds::UpperBounded;
-use num_traits::Zero;
 
-use crate::pixels::{GetCount, IntoPixelComponent, PixelComponent, PixelExt, Values};
+use crate::pixels::{PixelComponent, PixelExt, PixelLayout, U16x4, U8x4};
 use crate::{DynamicImageView, DynamicImageViewMut, MappingError};
 use crate::{ImageView, ImageViewMut};
 
+pub mod mappers;
+
 trait FromF32 {
     fn from_f32(x: f32) -> Self;
 }
@@ -21,3 +22,140 @@ impl FromF32 for u16 {
         x as Self
     }
 }
+
+/// Mapper of pixel components from one colorspace into another.
+///
+/// Components are mapped through lookup tables: forward mapping converts
+/// `U8x4` images into `U16x4` images, so that the precision isn't lost
+/// by non-linear transfer functions, and backward mapping converts them back.
+/// The last component of a pixel is treated as alpha channel
+/// and is only rescaled.
+#[derive(Debug, Clone)]
+pub struct PixelComponentMapper {
+    forward_mapping_table: Vec<u16>,
+    backward_mapping_table: Vec<u8>,
+}
+
+impl PixelComponentMapper {
+    /// Creates an instance of mapper.
+    ///
+    /// Both functions take and return values in range `[0.0, 1.0]`.
+    pub fn new<F, B>(forward_map: F, backward_map: B) -> Self
+    where
+        F: Fn(f32) -> f32,
+        B: Fn(f32) -> f32,
+    {
+        Self {
+            forward_mapping_table: create_mapping_table::<u8, u16>(&forward_map),
+            backward_mapping_table: create_mapping_table::<u16, u8>(&backward_map),
+        }
+    }
+
+    /// Maps components of source image and stores the result into destination image.
+    ///
+    /// Only `U8x4` source and `U16x4` destination images are supported.
+    pub fn forward_map(
+        &self,
+        src_image: &DynamicImageView,
+        dst_image: &mut DynamicImageViewMut,
+    ) -> Result<(), MappingError> {
+        match (src_image, dst_image) {
+            (DynamicImageView::U8x4(src), DynamicImageViewMut::U16x4(dst)) => {
+                check_dimensions(src, dst)?;
+                self.forward_map_u8x4(src, dst, PixelLayout::Rgba);
+                Ok(())
+            }
+            _ => Err(MappingError::UnsupportedCombinationOfImageTypes),
+        }
+    }
+
+    /// Maps components of source image back and stores the result into
+    /// destination image.
+    ///
+    /// Only `U16x4` source and `U8x4` destination images are supported.
+    pub fn backward_map(
+        &self,
+        src_image: &DynamicImageView,
+        dst_image: &mut DynamicImageViewMut,
+    ) -> Result<(), MappingError> {
+        match (src_image, dst_image) {
+            (DynamicImageView::U16x4(src), DynamicImageViewMut::U8x4(dst)) => {
+                check_dimensions(src, dst)?;
+                self.backward_map_u16x4(src, dst, PixelLayout::Rgba);
+                Ok(())
+            }
+            _ => Err(MappingError::UnsupportedCombinationOfImageTypes),
+        }
+    }
+
+    /// Maps the source image with given layout into an `Rgba` image.
+    pub(crate) fn forward_map_u8x4(
+        &self,
+        src_image: &ImageView<U8x4>,
+        dst_image: &mut ImageViewMut<U16x4>,
+        layout: PixelLayout,
+    ) {
+        let table = self.forward_mapping_table.as_slice();
+        let [ri, gi, bi, ai] = layout.indexes();
+        for (src_row, dst_row) in src_image.iter_rows(0).zip(dst_image.iter_rows_mut()) {
+            for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+                let p = src_pixel.0;
+                let [r, g, b, a] = [p[ri], p[gi], p[bi], p[ai]];
+                dst_pixel.0 = [
+                    table[r as usize],
+                    table[g as usize],
+                    table[b as usize],
+                    a as u16 * 257,
+                ];
+            }
+        }
+    }
+
+    /// Maps the source `Rgba` image into an image with given layout.
+    pub(crate) fn backward_map_u16x4(
+        &self,
+        src_image: &ImageView<U16x4>,
+        dst_image: &mut ImageViewMut<U8x4>,
+        layout: PixelLayout,
+    ) {
+        let table = self.backward_mapping_table.as_slice();
+        let [ri, gi, bi, ai] = layout.indexes();
+        for (src_row, dst_row) in src_image.iter_rows(0).zip(dst_image.iter_rows_mut()) {
+            for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+                let [r, g, b, a] = src_pixel.0;
+                let p = &mut dst_pixel.0;
+                p[ri] = table[r as usize];
+                p[gi] = table[g as usize];
+                p[bi] = table[b as usize];
+                p[ai] = ((a as u32 + 128) / 257) as u8;
+            }
+        }
+    }
+}
+
+fn check_dimensions<A: PixelExt, B: PixelExt>(
+    src_image: &ImageView<A>,
+    dst_image: &ImageViewMut<B>,
+) -> Result<(), MappingError> {
+    if src_image.width() != dst_image.width() || src_image.height() != dst_image.height() {
+        return Err(MappingError::DifferentDimensions);
+    }
+    Ok(())
+}
+
+/// Creates a table with a mapped value of `Out` type
+/// for every possible value of `In` type.
+fn create_mapping_table<In, Out>(map_func: &dyn Fn(f32) -> f32) -> Vec<Out>
+where
+    In: PixelComponent + UpperBounded + Into<f32>,
+    Out: PixelComponent + UpperBounded + Into<f32> + FromF32,
+{
+    let max_in: f32 = In::max_value().into();
+    let max_out: f32 = Out::max_value().into();
+    (0..In::count_of_values())
+        .map(|i| {
+            let v = map_func(i as f32 / max_in);
+            Out::from_f32((v * max_out).round().clamp(0.0, max_out))
+        })
+        .collect()
+}
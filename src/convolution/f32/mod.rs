@@ -0,0 +1,40 @@
+use crate::convolution::vertical_f32::vert_convolution_f32;
+use crate::pixels::F32;
+use crate::CpuExtensions;
+use crate::{ImageView, ImageViewMut};
+
+use super::{Coefficients, Convolution};
+
+#[cfg(target_feature = "simd128")]
+mod wasm32;
+mod native;
+
+impl Convolution for F32 {
+    fn horiz_convolution(
+        src_image: &ImageView<Self>,
+        dst_image: &mut ImageViewMut<Self>,
+        offset: u32,
+        coeffs: &Coefficients,
+        cpu_extensions: CpuExtensions,
+    ) {
+        match cpu_extensions {
+            #[cfg(target_feature = "simd128")]
+            CpuExtensions::Simd128 => {
+                wasm32::horiz_convolution(src_image, dst_image, offset, coeffs)
+            }
+            _ => {
+                native::horiz_convolution(src_image, dst_image, offset, coeffs)
+            }
+        }
+    }
+
+    fn vert_convolution(
+        src_image: &ImageView<Self>,
+        dst_image: &mut ImageViewMut<Self>,
+        offset: u32,
+        coeffs: &Coefficients,
+        cpu_extensions: CpuExtensions,
+    ) {
+        vert_convolution_f32(src_image, dst_image, offset, coeffs, cpu_extensions);
+    }
+}
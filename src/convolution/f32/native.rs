@@ -0,0 +1,29 @@
+use crate::convolution::Coefficients;
+use crate::pixels::F32;
+use crate::{ImageView, ImageViewMut};
+
+#[inline(always)]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<F32>,
+    dst_image: &mut ImageViewMut<F32>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let coefficients_chunks = coeffs.get_chunks();
+
+    let src_rows = src_image.iter_rows(offset);
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, src_row) in dst_rows.zip(src_rows) {
+        for (&coeffs_chunk, dst_pixel) in coefficients_chunks.iter().zip(dst_row.iter_mut()) {
+            let first_x_src = coeffs_chunk.start as usize;
+            let mut ss = 0.;
+            let src_pixels = unsafe { src_row.get_unchecked(first_x_src..) };
+
+            for (&k, &src_pixel) in coeffs_chunk.values.iter().zip(src_pixels) {
+                ss += src_pixel.0 as f64 * k;
+            }
+
+            dst_pixel.0 = ss as f32;
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::arch::wasm32::*;
+
+use crate::convolution::{Coefficients, CoefficientsChunk};
+use crate::pixels::F32;
+use crate::wasm32_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<F32>,
+    dst_image: &mut ImageViewMut<F32>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let coefficients_chunks = coeffs.get_chunks();
+
+    let src_rows = src_image.iter_rows(offset);
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, src_row) in dst_rows.zip(src_rows) {
+        unsafe {
+            horiz_convolution_row(src_row, dst_row, &coefficients_chunks);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "simd128")]
+unsafe fn horiz_convolution_row(
+    src_row: &[F32],
+    dst_row: &mut [F32],
+    coefficients_chunks: &[CoefficientsChunk],
+) {
+    unsafe {
+        for (dst_pixel, coeffs_chunk) in dst_row.iter_mut().zip(coefficients_chunks) {
+            let mut x: usize = coeffs_chunk.start as usize;
+            let mut sss = f64x2_splat(0.);
+
+            let coeffs_by_2 = coeffs_chunk.values.chunks_exact(2);
+            let reminder = coeffs_by_2.remainder();
+
+            for k in coeffs_by_2 {
+                // [64] k1 k0
+                let mmk = wasm32_utils::load_v128(k, 0);
+                // [32] x x p1 p0
+                let source = wasm32_utils::loadl_i64(src_row, x);
+                // [64] p1 p0
+                let pix = f64x2_promote_low_f32x4(source);
+                sss = f64x2_add(sss, f64x2_mul(pix, mmk));
+                x += 2;
+            }
+
+            let mut ss = f64x2_extract_lane::<0>(sss) + f64x2_extract_lane::<1>(sss);
+            if let Some(&k) = reminder.first() {
+                ss += src_row.get_unchecked(x).0 as f64 * k;
+            }
+
+            dst_pixel.0 = ss as f32;
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::convolution::Coefficients;
+use crate::pixels::F32x4;
+use crate::{ImageView, ImageViewMut};
+
+#[inline(always)]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<F32x4>,
+    dst_image: &mut ImageViewMut<F32x4>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let coefficients_chunks = coeffs.get_chunks();
+
+    let src_rows = src_image.iter_rows(offset);
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, src_row) in dst_rows.zip(src_rows) {
+        for (&coeffs_chunk, dst_pixel) in coefficients_chunks.iter().zip(dst_row.iter_mut()) {
+            let first_x_src = coeffs_chunk.start as usize;
+            let mut ss = [0.; 4];
+            let src_pixels = unsafe { src_row.get_unchecked(first_x_src..) };
+
+            for (&k, &src_pixel) in coeffs_chunk.values.iter().zip(src_pixels) {
+                for (i, s) in ss.iter_mut().enumerate() {
+                    *s += src_pixel.0[i] as f64 * k;
+                }
+            }
+
+            for (i, s) in ss.iter().copied().enumerate() {
+                dst_pixel.0[i] = s as f32;
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::arch::wasm32::*;
+
+use crate::convolution::{Coefficients, CoefficientsChunk};
+use crate::pixels::F32x4;
+use crate::wasm32_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<F32x4>,
+    dst_image: &mut ImageViewMut<F32x4>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let coefficients_chunks = coeffs.get_chunks();
+
+    let src_rows = src_image.iter_rows(offset);
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, src_row) in dst_rows.zip(src_rows) {
+        unsafe {
+            horiz_convolution_row(src_row, dst_row, &coefficients_chunks);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "simd128")]
+unsafe fn horiz_convolution_row(
+    src_row: &[F32x4],
+    dst_row: &mut [F32x4],
+    coefficients_chunks: &[CoefficientsChunk],
+) {
+    unsafe {
+        for (dst_pixel, coeffs_chunk) in dst_row.iter_mut().zip(coefficients_chunks) {
+            let x: usize = coeffs_chunk.start as usize;
+            // [64] g r
+            let mut sss_lo = f64x2_splat(0.);
+            // [64] a b
+            let mut sss_hi = f64x2_splat(0.);
+
+            let src_pixels = src_row.get_unchecked(x..);
+            for (&k, src_pixel) in coeffs_chunk.values.iter().zip(src_pixels) {
+                let mmk = f64x2_splat(k);
+                // [32] a b g r
+                let source = wasm32_utils::load_v128(std::slice::from_ref(src_pixel), 0);
+                let pix_lo = f64x2_promote_low_f32x4(source);
+                let pix_hi = f64x2_promote_low_f32x4(i64x2_shuffle::<1, 0>(source, source));
+                sss_lo = f64x2_add(sss_lo, f64x2_mul(pix_lo, mmk));
+                sss_hi = f64x2_add(sss_hi, f64x2_mul(pix_hi, mmk));
+            }
+
+            let result = i64x2_shuffle::<0, 2>(
+                f32x4_demote_f64x2_zero(sss_lo),
+                f32x4_demote_f64x2_zero(sss_hi),
+            );
+            v128_store(dst_pixel as *mut F32x4 as *mut v128, result);
+        }
+    }
+}
@@ -1,27 +1,37 @@
 pub use filters::{get_filter_func, FilterType};
 
+use std::ops::Range;
+
 use crate::pixels::PixelExt;
 use crate::CpuExtensions;
 use crate::{ImageView, ImageViewMut};
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(target_feature = "simd128")]
 #[macro_use]
 mod macros;
 
+mod f32;
+mod f32x4;
 mod filters;
 mod optimisations;
+mod u16x4;
+mod u8;
+mod u8x2;
+mod u8x3;
 mod u8x4;
+mod vertical_f32;
+mod vertical_u16;
 mod vertical_u8;
 
 pub(crate) trait Convolution
 where
-    Self: PixelExt,
+    Self: PixelExt + Send + Sync,
 {
     fn horiz_convolution(
         src_image: &ImageView<Self>,
         dst_image: &mut ImageViewMut<Self>,
         offset: u32,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     );
 
@@ -29,7 +39,7 @@ where
         src_image: &ImageView<Self>,
         dst_image: &mut ImageViewMut<Self>,
         offset: u32,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     );
 }
@@ -45,14 +55,49 @@ pub struct Coefficients {
     pub values: Vec<f64>,
     pub window_size: usize,
     pub bounds: Vec<Bound>,
+    /// Maximum of the values of all windows, including the ones removed by
+    /// [Coefficients::slice], from which integer normalizers choose their precision.
+    pub max_weight: f64,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
 pub struct CoefficientsChunk<'a> {
     pub start: u32,
     pub values: &'a [f64],
 }
 
+impl Coefficients {
+    pub fn get_chunks(&self) -> Vec<CoefficientsChunk<'_>> {
+        let mut coeffs = self.values.as_slice();
+        let mut res = Vec::with_capacity(self.bounds.len());
+        for bound in &self.bounds {
+            let (left, right) = coeffs.split_at(self.window_size);
+            coeffs = right;
+            let size = bound.size as usize;
+            res.push(CoefficientsChunk {
+                start: bound.start,
+                values: &left[0..size],
+            });
+        }
+        res
+    }
+
+    /// Returns coefficients of the destination rows (or columns) in `range`.
+    ///
+    /// The maximum weight is kept, so that the integer normalizers choose
+    /// the same precision as for the whole coefficients.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        let values = &self.values[range.start * self.window_size..range.end * self.window_size];
+        Self {
+            values: values.to_vec(),
+            window_size: self.window_size,
+            bounds: self.bounds[range].to_vec(),
+            max_weight: self.max_weight,
+        }
+    }
+}
+
 pub fn precompute_coefficients(
     in_size: usize,
     in0: f64, // Left border for cropping
@@ -111,9 +156,11 @@ pub fn precompute_coefficients(
         });
     }
 
+    let max_weight = coeffs.iter().copied().reduce(f64::max).unwrap_or(0.0);
     Coefficients {
         values: coeffs,
         window_size,
         bounds,
+        max_weight,
     }
 }
@@ -45,13 +45,8 @@ pub(crate) struct CoefficientsI16Chunk<'a> {
 
 impl Normalizer16 {
     #[inline]
-    pub fn new(coefficients: Coefficients) -> Self {
-        let max_weight = coefficients
-            .values
-            .iter()
-            .max_by(|&x, &y| x.partial_cmp(y).unwrap())
-            .unwrap_or(&0.0)
-            .to_owned();
+    pub fn new(coefficients: &Coefficients) -> Self {
+        let max_weight = coefficients.max_weight;
 
         let mut precision = 0u8;
         for cur_precision in 0..PRECISION_BITS {
@@ -74,12 +69,12 @@ impl Normalizer16 {
             values: values_i16,
             precision,
             window_size: coefficients.window_size,
-            bounds: coefficients.bounds,
+            bounds: coefficients.bounds.clone(),
         }
     }
 
     #[inline]
-    pub fn normalized_chunks(&self) -> Vec<CoefficientsI16Chunk> {
+    pub fn normalized_chunks(&self) -> Vec<CoefficientsI16Chunk<'_>> {
         let mut cooefs = self.values.as_slice();
         let mut res = Vec::with_capacity(self.bounds.len());
         for bound in self.bounds.iter() {
@@ -113,3 +108,81 @@ impl Normalizer16 {
 }
 
 
+
+// 16 bits for result. Filter can have negative areas.
+// In one cases the sum of the coefficients will be negative,
+// in the other it will be more than 1.0. That is why we need
+// two extra bits for overflow and i64 type.
+const PRECISION16_BITS: u8 = 64 - 16 - 2;
+// We use i32 type to store coefficients.
+const MAX_COEFFS_PRECISION32: u8 = 32 - 1;
+
+/// Converts `Vec<f64>` into `Vec<i32>`.
+pub(crate) struct Normalizer32 {
+    values: Vec<i32>,
+    precision: u8,
+    window_size: usize,
+    bounds: Vec<Bound>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CoefficientsI32Chunk<'a> {
+    pub start: u32,
+    pub values: &'a [i32],
+}
+
+impl Normalizer32 {
+    #[inline]
+    pub fn new(coefficients: &Coefficients) -> Self {
+        let max_weight = coefficients.max_weight;
+
+        let mut precision = 0u8;
+        for cur_precision in 0..PRECISION16_BITS {
+            precision = cur_precision;
+            let next_value: i64 = (max_weight * (1i64 << (precision + 1)) as f64).round() as i64;
+            // The next value will be outside the range, so just stop
+            if next_value >= (1i64 << MAX_COEFFS_PRECISION32) {
+                break;
+            }
+        }
+
+        let mut values_i32 = Vec::with_capacity(coefficients.values.len());
+
+        let scale = (1i64 << precision) as f64;
+        for src in coefficients.values.iter().copied() {
+            values_i32.push((src * scale).round() as i32);
+        }
+        Self {
+            values: values_i32,
+            precision,
+            window_size: coefficients.window_size,
+            bounds: coefficients.bounds.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn normalized_chunks(&self) -> Vec<CoefficientsI32Chunk<'_>> {
+        let mut cooefs = self.values.as_slice();
+        let mut res = Vec::with_capacity(self.bounds.len());
+        for bound in self.bounds.iter() {
+            let (left, right) = cooefs.split_at(self.window_size);
+            cooefs = right;
+            let size = bound.size as usize;
+            res.push(CoefficientsI32Chunk {
+                start: bound.start,
+                values: &left[0..size],
+            });
+        }
+        res
+    }
+
+    #[inline]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    #[inline(always)]
+    pub fn clip(&self, v: i64) -> u16 {
+        (v >> self.precision).clamp(0, u16::MAX as i64) as u16
+    }
+}
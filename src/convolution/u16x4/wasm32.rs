@@ -0,0 +1,84 @@
+use std::arch::wasm32::*;
+
+use crate::convolution::{optimisations, Coefficients};
+use crate::pixels::U16x4;
+use crate::wasm32_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<U16x4>,
+    dst_image: &mut ImageViewMut<U16x4>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer = optimisations::Normalizer32::new(coeffs);
+    let coefficients_chunks = normalizer.normalized_chunks();
+
+    let src_rows = src_image.iter_rows(offset);
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, src_row) in dst_rows.zip(src_rows) {
+        unsafe {
+            horiz_convolution_row(src_row, dst_row, &coefficients_chunks, &normalizer);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "simd128")]
+unsafe fn horiz_convolution_row(
+    src_row: &[U16x4],
+    dst_row: &mut [U16x4],
+    coefficients_chunks: &[optimisations::CoefficientsI32Chunk],
+    normalizer: &optimisations::Normalizer32,
+) {
+    unsafe {
+        let initial = i64x2_splat(1 << (normalizer.precision() - 1));
+
+        for (dst_pixel, coeffs_chunk) in dst_row.iter_mut().zip(coefficients_chunks) {
+            let mut x: usize = coeffs_chunk.start as usize;
+            // [64] g r
+            let mut sss_lo = initial;
+            // [64] a b
+            let mut sss_hi = initial;
+
+            let coeffs_by_2 = coeffs_chunk.values.chunks_exact(2);
+            let reminder = coeffs_by_2.remainder();
+
+            for k in coeffs_by_2 {
+                let mmk0 = i32x4_splat(k[0]);
+                let mmk1 = i32x4_splat(k[1]);
+
+                // [16] a1 b1 g1 r1 a0 b0 g0 r0
+                let source = wasm32_utils::load_v128(src_row, x);
+                // [32] a0 b0 g0 r0
+                let mut pix = u32x4_extend_low_u16x8(source);
+                sss_lo = i64x2_add(sss_lo, i64x2_extmul_low_i32x4(pix, mmk0));
+                sss_hi = i64x2_add(sss_hi, i64x2_extmul_high_i32x4(pix, mmk0));
+                // [32] a1 b1 g1 r1
+                pix = u32x4_extend_high_u16x8(source);
+                sss_lo = i64x2_add(sss_lo, i64x2_extmul_low_i32x4(pix, mmk1));
+                sss_hi = i64x2_add(sss_hi, i64x2_extmul_high_i32x4(pix, mmk1));
+                x += 2;
+            }
+
+            if let Some(&k) = reminder.first() {
+                let mmk = i32x4_splat(k);
+                // [16] x x x x a0 b0 g0 r0
+                let source = wasm32_utils::loadl_i64(src_row, x);
+                let pix = u32x4_extend_low_u16x8(source);
+                sss_lo = i64x2_add(sss_lo, i64x2_extmul_low_i32x4(pix, mmk));
+                sss_hi = i64x2_add(sss_hi, i64x2_extmul_high_i32x4(pix, mmk));
+            }
+
+            dst_pixel.0 = [
+                normalizer.clip(i64x2_extract_lane::<0>(sss_lo)),
+                normalizer.clip(i64x2_extract_lane::<1>(sss_lo)),
+                normalizer.clip(i64x2_extract_lane::<0>(sss_hi)),
+                normalizer.clip(i64x2_extract_lane::<1>(sss_hi)),
+            ];
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::arch::wasm32::*;
+
+use crate::convolution::{optimisations, Coefficients};
+use crate::pixels::{PixelExt, U8};
+use crate::wasm32_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<U8>,
+    dst_image: &mut ImageViewMut<U8>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer = optimisations::Normalizer16::new(coeffs);
+    let coefficients_chunks = normalizer.normalized_chunks();
+
+    let src_rows = src_image.iter_rows(offset);
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, src_row) in dst_rows.zip(src_rows) {
+        unsafe {
+            horiz_convolution_row(src_row, dst_row, &coefficients_chunks, &normalizer);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "simd128")]
+unsafe fn horiz_convolution_row(
+    src_row: &[U8],
+    dst_row: &mut [U8],
+    coefficients_chunks: &[optimisations::CoefficientsI16Chunk],
+    normalizer: &optimisations::Normalizer16,
+) {
+    unsafe {
+        let src = U8::components(src_row);
+        let initial = 1 << (normalizer.precision() - 1);
+
+        for (dst_pixel, coeffs_chunk) in dst_row.iter_mut().zip(coefficients_chunks) {
+            let mut x: usize = coeffs_chunk.start as usize;
+            let mut sss = i32x4_splat(0);
+
+            let coeffs_by_8 = coeffs_chunk.values.chunks_exact(8);
+            let reminder = coeffs_by_8.remainder();
+
+            for k in coeffs_by_8 {
+                // [16] k7 k6 k5 k4 k3 k2 k1 k0
+                let mmk = wasm32_utils::load_v128(k, 0);
+                // [16] s7 s6 s5 s4 s3 s2 s1 s0
+                let pix = u16x8_extend_low_u8x16(wasm32_utils::loadl_i64(src, x));
+                sss = i32x4_add(sss, i32x4_dot_i16x8(pix, mmk));
+                x += 8;
+            }
+
+            let mut ss = initial
+                + i32x4_extract_lane::<0>(sss)
+                + i32x4_extract_lane::<1>(sss)
+                + i32x4_extract_lane::<2>(sss)
+                + i32x4_extract_lane::<3>(sss);
+            for &k in reminder {
+                ss += *src.get_unchecked(x) as i32 * (k as i32);
+                x += 1;
+            }
+
+            dst_pixel.0 = normalizer.clip(ss);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::arch::wasm32::*;
+
+use crate::convolution::{optimisations, Coefficients};
+use crate::pixels::U8x2;
+use crate::wasm32_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<U8x2>,
+    dst_image: &mut ImageViewMut<U8x2>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer = optimisations::Normalizer16::new(coeffs);
+    let coefficients_chunks = normalizer.normalized_chunks();
+
+    let src_rows = src_image.iter_rows(offset);
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, src_row) in dst_rows.zip(src_rows) {
+        unsafe {
+            horiz_convolution_row(src_row, dst_row, &coefficients_chunks, &normalizer);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "simd128")]
+unsafe fn horiz_convolution_row(
+    src_row: &[U8x2],
+    dst_row: &mut [U8x2],
+    coefficients_chunks: &[optimisations::CoefficientsI16Chunk],
+    normalizer: &optimisations::Normalizer16,
+) {
+    unsafe {
+        let initial = 1 << (normalizer.precision() - 1);
+        const MASK: v128 = i8x16(0, -1, 2, -1, 1, -1, 3, -1, 4, -1, 6, -1, 5, -1, 7, -1);
+
+        for (dst_pixel, coeffs_chunk) in dst_row.iter_mut().zip(coefficients_chunks) {
+            let mut x: usize = coeffs_chunk.start as usize;
+            let mut sss = i32x4_splat(0);
+
+            let coeffs_by_4 = coeffs_chunk.values.chunks_exact(4);
+            let reminder = coeffs_by_4.remainder();
+
+            for k in coeffs_by_4 {
+                // [16] k3 k2 k3 k2 k1 k0 k1 k0
+                let mmk = i32x4_shuffle::<0, 1, 4, 5>(
+                    wasm32_utils::ptr_i16_to_set1_i32(k, 0),
+                    wasm32_utils::ptr_i16_to_set1_i32(k, 2),
+                );
+                // [8] x x x x x x x x a3 l3 a2 l2 a1 l1 a0 l0
+                let source = wasm32_utils::loadl_i64(src_row, x);
+                // [16] a3 a2 l3 l2 a1 a0 l1 l0
+                let pix = i8x16_swizzle(source, MASK);
+                sss = i32x4_add(sss, i32x4_dot_i16x8(pix, mmk));
+                x += 4;
+            }
+
+            let mut ss = [
+                initial + i32x4_extract_lane::<0>(sss) + i32x4_extract_lane::<2>(sss),
+                initial + i32x4_extract_lane::<1>(sss) + i32x4_extract_lane::<3>(sss),
+            ];
+            for &k in reminder {
+                let src_pixel = src_row.get_unchecked(x);
+                for (i, s) in ss.iter_mut().enumerate() {
+                    *s += src_pixel.0[i] as i32 * (k as i32);
+                }
+                x += 1;
+            }
+
+            for (i, s) in ss.iter().copied().enumerate() {
+                dst_pixel.0[i] = normalizer.clip(s);
+            }
+        }
+    }
+}
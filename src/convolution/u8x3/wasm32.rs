@@ -0,0 +1,85 @@
+use std::arch::wasm32::*;
+
+use crate::convolution::{optimisations, Coefficients};
+use crate::pixels::U8x3;
+use crate::wasm32_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<U8x3>,
+    dst_image: &mut ImageViewMut<U8x3>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer = optimisations::Normalizer16::new(coeffs);
+    let coefficients_chunks = normalizer.normalized_chunks();
+
+    let src_rows = src_image.iter_rows(offset);
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, src_row) in dst_rows.zip(src_rows) {
+        unsafe {
+            horiz_convolution_row(src_row, dst_row, &coefficients_chunks, &normalizer);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "simd128")]
+unsafe fn horiz_convolution_row(
+    src_row: &[U8x3],
+    dst_row: &mut [U8x3],
+    coefficients_chunks: &[optimisations::CoefficientsI16Chunk],
+    normalizer: &optimisations::Normalizer16,
+) {
+    unsafe {
+        let initial = 1 << (normalizer.precision() - 1);
+        const MASK_LO: v128 = i8x16(0, -1, 3, -1, 1, -1, 4, -1, 2, -1, 5, -1, -1, -1, -1, -1);
+        const MASK_HI: v128 = i8x16(6, -1, 9, -1, 7, -1, 10, -1, 8, -1, 11, -1, -1, -1, -1, -1);
+
+        for (dst_pixel, coeffs_chunk) in dst_row.iter_mut().zip(coefficients_chunks) {
+            let mut x: usize = coeffs_chunk.start as usize;
+            let mut sss = i32x4_splat(0);
+
+            // Every load reads 16 bytes (5 and 1/3 pixels) to use 4 pixels,
+            // so it is necessary to stop before the end of the source row.
+            let simd_count = src_row.len().saturating_sub(x + 2) / 4;
+            let coeffs_count = coeffs_chunk.values.len().min(simd_count * 4) & !3;
+            let (simd_coeffs, reminder) = coeffs_chunk.values.split_at(coeffs_count);
+
+            for k in simd_coeffs.chunks_exact(4) {
+                let mmk_lo = wasm32_utils::ptr_i16_to_set1_i32(k, 0);
+                let mmk_hi = wasm32_utils::ptr_i16_to_set1_i32(k, 2);
+
+                // [8] x x x x b3 g3 r3 b2 g2 r2 b1 g1 r1 b0 g0 r0
+                let source = wasm32_utils::load_v128(src_row, x);
+                // [16] x x b1 b0 g1 g0 r1 r0
+                let mut pix = i8x16_swizzle(source, MASK_LO);
+                sss = i32x4_add(sss, i32x4_dot_i16x8(pix, mmk_lo));
+                // [16] x x b3 b2 g3 g2 r3 r2
+                pix = i8x16_swizzle(source, MASK_HI);
+                sss = i32x4_add(sss, i32x4_dot_i16x8(pix, mmk_hi));
+                x += 4;
+            }
+
+            let mut ss = [
+                initial + i32x4_extract_lane::<0>(sss),
+                initial + i32x4_extract_lane::<1>(sss),
+                initial + i32x4_extract_lane::<2>(sss),
+            ];
+            for &k in reminder {
+                let src_pixel = src_row.get_unchecked(x);
+                for (i, s) in ss.iter_mut().enumerate() {
+                    *s += src_pixel.0[i] as i32 * (k as i32);
+                }
+                x += 1;
+            }
+
+            for (i, s) in ss.iter().copied().enumerate() {
+                dst_pixel.0[i] = normalizer.clip(s);
+            }
+        }
+    }
+}
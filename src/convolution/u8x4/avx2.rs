@@ -0,0 +1,155 @@
+use std::arch::x86_64::*;
+
+use crate::convolution::{optimisations, Coefficients};
+use crate::pixels::U8x4;
+use crate::x86_64_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: &ImageView<U8x4>,
+    dst_image: &mut ImageViewMut<U8x4>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer = optimisations::Normalizer16::new(coeffs);
+    let precision = normalizer.precision();
+    let coefficients_chunks = normalizer.normalized_chunks();
+    let dst_height = dst_image.height();
+
+    let src_iter = src_image.iter_4_rows(offset, dst_height as u32 + offset);
+    let dst_iter = dst_image.iter_4_rows_mut();
+    for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
+        unsafe {
+            horiz_convolution_rows(src_rows, dst_rows, &coefficients_chunks, precision);
+        }
+    }
+
+    let mut yy = dst_height - dst_height % 4;
+    while yy < dst_height {
+        unsafe {
+            horiz_convolution_rows(
+                [src_image.get_row(yy + offset as usize).unwrap()],
+                [dst_image.get_row_mut(yy).unwrap()],
+                &coefficients_chunks,
+                precision,
+            );
+        }
+        yy += 1;
+    }
+}
+
+/// Convolves `N` rows at once, so that the shuffled coefficients are reused.
+/// Every iteration of the main loop takes 8 source pixels of each row.
+///
+/// For safety, it is necessary to ensure the following conditions:
+/// - length of all rows in src_rows must be equal
+/// - length of all rows in dst_rows must be equal
+/// - coefficients_chunks.len() == dst_rows.0.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.0.len()
+/// - precision <= MAX_COEFS_PRECISION
+#[target_feature(enable = "avx2")]
+unsafe fn horiz_convolution_rows<const N: usize>(
+    src_rows: [&[U8x4]; N],
+    mut dst_rows: [&mut &mut [U8x4]; N],
+    coefficients_chunks: &[optimisations::CoefficientsI16Chunk],
+    precision: u8,
+) {
+    unsafe {
+        let initial = _mm_set1_epi32(1 << (precision - 1));
+        let shift = _mm_cvtsi32_si128(precision as i32);
+        let mask_lo = _mm256_set_epi8(
+            -1, 7, -1, 3, -1, 6, -1, 2, -1, 5, -1, 1, -1, 4, -1, 0,
+            -1, 7, -1, 3, -1, 6, -1, 2, -1, 5, -1, 1, -1, 4, -1, 0,
+        );
+        let mask_hi = _mm256_set_epi8(
+            -1, 15, -1, 11, -1, 14, -1, 10, -1, 13, -1, 9, -1, 12, -1, 8,
+            -1, 15, -1, 11, -1, 14, -1, 10, -1, 13, -1, 9, -1, 12, -1, 8,
+        );
+        // Pairs of coefficients for the lower and the upper lane.
+        let k_lo_index = _mm256_set_epi32(2, 2, 2, 2, 0, 0, 0, 0);
+        let k_hi_index = _mm256_set_epi32(3, 3, 3, 3, 1, 1, 1, 1);
+        let mask_lo_128 = _mm256_castsi256_si128(mask_lo);
+        let mask_hi_128 = _mm256_castsi256_si128(mask_hi);
+
+        for (dst_x, coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+            let mut x: usize = coeffs_chunk.start as usize;
+
+            let mut sss256 = [_mm256_setzero_si256(); N];
+
+            let coeffs = coeffs_chunk.values;
+            let coeffs_by_8 = coeffs.chunks_exact(8);
+            let reminder8 = coeffs_by_8.remainder();
+
+            for k in coeffs_by_8 {
+                let ksource = _mm256_castsi128_si256(x86_64_utils::loadu_si128(k, 0));
+                let mmk_lo = _mm256_permutevar8x32_epi32(ksource, k_lo_index);
+                let mmk_hi = _mm256_permutevar8x32_epi32(ksource, k_hi_index);
+
+                for (s, src_row) in sss256.iter_mut().zip(src_rows) {
+                    // [8] a7 b7 g7 r7 ... a4 b4 g4 r4 | a3 b3 g3 r3 ... a0 b0 g0 r0
+                    let source = x86_64_utils::loadu_si256(src_row, x);
+                    // [16] a5 a4 b5 b4 g5 g4 r5 r4 | a1 a0 b1 b0 g1 g0 r1 r0
+                    let mut pix = _mm256_shuffle_epi8(source, mask_lo);
+                    *s = _mm256_add_epi32(*s, _mm256_madd_epi16(pix, mmk_lo));
+                    // [16] a7 a6 b7 b6 g7 g6 r7 r6 | a3 a2 b3 b2 g3 g2 r3 r2
+                    pix = _mm256_shuffle_epi8(source, mask_hi);
+                    *s = _mm256_add_epi32(*s, _mm256_madd_epi16(pix, mmk_hi));
+                }
+                x += 8;
+            }
+
+            let mut sss = [initial; N];
+            for (s, s256) in sss.iter_mut().zip(sss256) {
+                *s = _mm_add_epi32(*s, x86_64_utils::mm256_hadd_lanes_epi32(s256));
+            }
+
+            let coeffs_by_4 = reminder8.chunks_exact(4);
+            let reminder4 = coeffs_by_4.remainder();
+
+            for k in coeffs_by_4 {
+                let mmk_lo = x86_64_utils::ptr_i16_to_set1_epi32(k, 0);
+                let mmk_hi = x86_64_utils::ptr_i16_to_set1_epi32(k, 2);
+
+                for (s, src_row) in sss.iter_mut().zip(src_rows) {
+                    let source = x86_64_utils::loadu_si128(src_row, x);
+                    let mut pix = _mm_shuffle_epi8(source, mask_lo_128);
+                    *s = _mm_add_epi32(*s, _mm_madd_epi16(pix, mmk_lo));
+                    pix = _mm_shuffle_epi8(source, mask_hi_128);
+                    *s = _mm_add_epi32(*s, _mm_madd_epi16(pix, mmk_hi));
+                }
+                x += 4;
+            }
+
+            let coeffs_by_2 = reminder4.chunks_exact(2);
+            let reminder2 = coeffs_by_2.remainder();
+
+            for k in coeffs_by_2 {
+                let mmk = x86_64_utils::ptr_i16_to_set1_epi32(k, 0);
+
+                for (s, src_row) in sss.iter_mut().zip(src_rows) {
+                    let pix = x86_64_utils::loadl_epi64(src_row, x);
+                    let pix = _mm_shuffle_epi8(pix, mask_lo_128);
+                    *s = _mm_add_epi32(*s, _mm_madd_epi16(pix, mmk));
+                }
+                x += 2;
+            }
+
+            if let Some(&k) = reminder2.first() {
+                let mmk = _mm_set1_epi32(k as i32);
+
+                for (s, src_row) in sss.iter_mut().zip(src_rows) {
+                    let pix = x86_64_utils::mm_cvtepu8_epi32_u8x4(src_row, x);
+                    *s = _mm_add_epi32(*s, _mm_madd_epi16(pix, mmk));
+                }
+            }
+
+            for (s, dst_row) in sss.into_iter().zip(dst_rows.iter_mut()) {
+                let s = _mm_sra_epi32(s, shift);
+                let s = _mm_packs_epi32(s, s);
+                *dst_row.get_unchecked_mut(dst_x) =
+                    x86_64_utils::mm_cvtsi128_u8x4(_mm_packus_epi16(s, s));
+            }
+        }
+    }
+}
@@ -5,10 +5,13 @@ use crate::{ImageView, ImageViewMut};
 
 use super::{Coefficients, Convolution};
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+#[cfg(target_feature = "simd128")]
 mod wasm32;
-#[cfg(not(target_arch = "wasm32"))]
 mod native;
+#[cfg(target_arch = "x86_64")]
+mod sse4;
 
 
 impl Convolution for U8x4 {
@@ -16,15 +19,22 @@ impl Convolution for U8x4 {
         src_image: &ImageView<Self>,
         dst_image: &mut ImageViewMut<Self>,
         offset: u32,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     ) {
         match cpu_extensions {
-            #[cfg(target_arch = "wasm32")]
+            #[cfg(target_feature = "simd128")]
             CpuExtensions::Simd128 => {
                 wasm32::horiz_convolution(src_image, dst_image, offset, coeffs)
             }
-            #[cfg(not(target_arch = "wasm32"))]
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Avx2 => {
+                avx2::horiz_convolution(src_image, dst_image, offset, coeffs)
+            }
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Sse4_1 => {
+                sse4::horiz_convolution(src_image, dst_image, offset, coeffs)
+            }
             CpuExtensions::None => {
                 native::horiz_convolution(src_image, dst_image, offset, coeffs)
             }
@@ -35,7 +45,7 @@ impl Convolution for U8x4 {
         src_image: &ImageView<Self>,
         dst_image: &mut ImageViewMut<Self>,
         offset: u32,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     ) {
         vert_convolution_u8(src_image, dst_image, offset, coeffs, cpu_extensions);
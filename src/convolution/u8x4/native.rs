@@ -7,7 +7,7 @@ pub(crate) fn horiz_convolution(
     src_image: &ImageView<U8x4>,
     dst_image: &mut ImageViewMut<U8x4>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
     let normalizer = optimisations::Normalizer16::new(coeffs);
     let precision = normalizer.precision();
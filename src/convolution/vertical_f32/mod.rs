@@ -0,0 +1,22 @@
+use crate::convolution::Coefficients;
+use crate::pixels::PixelExt;
+use crate::CpuExtensions;
+use crate::{ImageView, ImageViewMut};
+
+pub(crate) mod native;
+#[cfg(target_feature = "simd128")]
+pub(crate) mod wasm32;
+
+pub(crate) fn vert_convolution_f32<T: PixelExt<Component = f32>>(
+    src_image: &ImageView<T>,
+    dst_image: &mut ImageViewMut<T>,
+    offset: u32,
+    coeffs: &Coefficients,
+    cpu_extensions: CpuExtensions,
+) {
+    match cpu_extensions {
+        #[cfg(target_feature = "simd128")]
+        CpuExtensions::Simd128 => wasm32::vert_convolution(src_image, dst_image, offset, coeffs),
+        _ => native::vert_convolution(src_image, dst_image, offset, coeffs),
+    }
+}
@@ -0,0 +1,35 @@
+use crate::convolution::Coefficients;
+use crate::pixels::PixelExt;
+use crate::{ImageView, ImageViewMut};
+
+#[inline(always)]
+pub(crate) fn vert_convolution<T>(
+    src_image: &ImageView<T>,
+    dst_image: &mut ImageViewMut<T>,
+    offset: u32,
+    coeffs: &Coefficients,
+) where
+    T: PixelExt<Component = f32>,
+{
+    let coefficients_chunks = coeffs.get_chunks();
+    let src_x_initial = offset as usize * T::count_of_components();
+    let dst_width = dst_image.width() * T::count_of_components();
+    // Sums of one row of destination image.
+    let mut ss = vec![0.; dst_width];
+
+    let dst_rows = dst_image.iter_rows_mut();
+    for (coeffs_chunk, dst_row) in coefficients_chunks.into_iter().zip(dst_rows) {
+        ss.fill(0.);
+        let src_rows = src_image.iter_rows(coeffs_chunk.start);
+        for (&k, src_row) in coeffs_chunk.values.iter().zip(src_rows) {
+            let src_components = &T::components(src_row)[src_x_initial..];
+            for (s, &c) in ss.iter_mut().zip(src_components) {
+                *s += c as f64 * k;
+            }
+        }
+        let dst_components = T::components_mut(dst_row);
+        for (dst_component, &s) in dst_components.iter_mut().zip(&ss) {
+            *dst_component = s as f32;
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::arch::wasm32::*;
+
+use crate::convolution::{Coefficients, CoefficientsChunk};
+use crate::pixels::PixelExt;
+use crate::wasm32_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn vert_convolution<T>(
+    src_image: &ImageView<T>,
+    dst_image: &mut ImageViewMut<T>,
+    offset: u32,
+    coeffs: &Coefficients,
+) where
+    T: PixelExt<Component = f32>,
+{
+    let coefficients_chunks = coeffs.get_chunks();
+    let src_x_initial = offset as usize * T::count_of_components();
+
+    let dst_rows = dst_image.iter_rows_mut();
+    for (&coeffs_chunk, dst_row) in coefficients_chunks.iter().zip(dst_rows) {
+        unsafe {
+            vert_convolution_into_one_row(
+                src_image,
+                T::components_mut(dst_row),
+                src_x_initial,
+                coeffs_chunk,
+            );
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - src_x_initial + dst_components.len() <= length of components of every source row
+/// - coeffs_chunk.start + coeffs_chunk.values.len() <= src_image.height()
+#[target_feature(enable = "simd128")]
+unsafe fn vert_convolution_into_one_row<T>(
+    src_image: &ImageView<T>,
+    dst_components: &mut [f32],
+    src_x_initial: usize,
+    coeffs_chunk: CoefficientsChunk,
+) where
+    T: PixelExt<Component = f32>,
+{
+    unsafe {
+        let width = dst_components.len();
+        let mut x: usize = 0;
+
+        while x + 4 <= width {
+            let mut sss_lo = f64x2_splat(0.);
+            let mut sss_hi = f64x2_splat(0.);
+            let src_rows = src_image.iter_rows(coeffs_chunk.start);
+            for (&k, src_row) in coeffs_chunk.values.iter().zip(src_rows) {
+                let mmk = f64x2_splat(k);
+                let src_components = T::components(src_row);
+                // [32] c3 c2 c1 c0
+                let source = wasm32_utils::load_v128(src_components, src_x_initial + x);
+                let pix_lo = f64x2_promote_low_f32x4(source);
+                let pix_hi = f64x2_promote_low_f32x4(i64x2_shuffle::<1, 0>(source, source));
+                sss_lo = f64x2_add(sss_lo, f64x2_mul(pix_lo, mmk));
+                sss_hi = f64x2_add(sss_hi, f64x2_mul(pix_hi, mmk));
+            }
+            let result = i64x2_shuffle::<0, 2>(
+                f32x4_demote_f64x2_zero(sss_lo),
+                f32x4_demote_f64x2_zero(sss_hi),
+            );
+            let dst_ptr = dst_components.get_unchecked_mut(x..).as_mut_ptr() as *mut v128;
+            v128_store(dst_ptr, result);
+            x += 4;
+        }
+
+        for (dst_x, dst_component) in dst_components.iter_mut().enumerate().skip(x) {
+            let mut ss = 0.;
+            let src_rows = src_image.iter_rows(coeffs_chunk.start);
+            for (&k, src_row) in coeffs_chunk.values.iter().zip(src_rows) {
+                let src_component = *T::components(src_row).get_unchecked(src_x_initial + dst_x);
+                ss += src_component as f64 * k;
+            }
+            *dst_component = ss as f32;
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::convolution::{optimisations, Coefficients};
+use crate::pixels::PixelExt;
+use crate::{ImageView, ImageViewMut};
+
+#[inline(always)]
+pub(crate) fn vert_convolution<T>(
+    src_image: &ImageView<T>,
+    dst_image: &mut ImageViewMut<T>,
+    offset: u32,
+    coeffs: &Coefficients,
+) where
+    T: PixelExt<Component = u16>,
+{
+    let normalizer = optimisations::Normalizer32::new(coeffs);
+    let coefficients_chunks = normalizer.normalized_chunks();
+    let precision = normalizer.precision();
+    let initial: i64 = 1 << (precision - 1);
+    let src_x_initial = offset as usize * T::count_of_components();
+    let dst_width = dst_image.width() * T::count_of_components();
+    // Sums of one row of destination image.
+    let mut ss = vec![initial; dst_width];
+
+    let dst_rows = dst_image.iter_rows_mut();
+    for (coeffs_chunk, dst_row) in coefficients_chunks.into_iter().zip(dst_rows) {
+        ss.fill(initial);
+        let src_rows = src_image.iter_rows(coeffs_chunk.start);
+        for (&k, src_row) in coeffs_chunk.values.iter().zip(src_rows) {
+            let src_components = &T::components(src_row)[src_x_initial..];
+            for (s, &c) in ss.iter_mut().zip(src_components) {
+                *s += c as i64 * (k as i64);
+            }
+        }
+        let dst_components = T::components_mut(dst_row);
+        for (dst_component, &s) in dst_components.iter_mut().zip(&ss) {
+            *dst_component = normalizer.clip(s);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::arch::wasm32::*;
+
+use crate::convolution::{optimisations, Coefficients};
+use crate::pixels::PixelExt;
+use crate::wasm32_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn vert_convolution<T>(
+    src_image: &ImageView<T>,
+    dst_image: &mut ImageViewMut<T>,
+    offset: u32,
+    coeffs: &Coefficients,
+) where
+    T: PixelExt<Component = u16>,
+{
+    let normalizer = optimisations::Normalizer32::new(coeffs);
+    let coefficients_chunks = normalizer.normalized_chunks();
+    let src_x_initial = offset as usize * T::count_of_components();
+
+    let dst_rows = dst_image.iter_rows_mut();
+    for (&coeffs_chunk, dst_row) in coefficients_chunks.iter().zip(dst_rows) {
+        unsafe {
+            vert_convolution_into_one_row(
+                src_image,
+                T::components_mut(dst_row),
+                src_x_initial,
+                coeffs_chunk,
+                &normalizer,
+            );
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - src_x_initial + dst_components.len() <= length of components of every source row
+/// - coeffs_chunk.start + coeffs_chunk.values.len() <= src_image.height()
+#[target_feature(enable = "simd128")]
+unsafe fn vert_convolution_into_one_row<T>(
+    src_image: &ImageView<T>,
+    dst_components: &mut [u16],
+    src_x_initial: usize,
+    coeffs_chunk: optimisations::CoefficientsI32Chunk,
+    normalizer: &optimisations::Normalizer32,
+) where
+    T: PixelExt<Component = u16>,
+{
+    unsafe {
+        let initial: i64 = 1 << (normalizer.precision() - 1);
+        let width = dst_components.len();
+        let mut x: usize = 0;
+
+        while x + 8 <= width {
+            let mut sss = [i64x2_splat(initial); 4];
+            let src_rows = src_image.iter_rows(coeffs_chunk.start);
+            for (&k, src_row) in coeffs_chunk.values.iter().zip(src_rows) {
+                let mmk = i32x4_splat(k);
+                let src_components = T::components(src_row);
+                // [16] c7 c6 c5 c4 c3 c2 c1 c0
+                let source = wasm32_utils::load_v128(src_components, src_x_initial + x);
+                let pix_lo = u32x4_extend_low_u16x8(source);
+                let pix_hi = u32x4_extend_high_u16x8(source);
+                sss[0] = i64x2_add(sss[0], i64x2_extmul_low_i32x4(pix_lo, mmk));
+                sss[1] = i64x2_add(sss[1], i64x2_extmul_high_i32x4(pix_lo, mmk));
+                sss[2] = i64x2_add(sss[2], i64x2_extmul_low_i32x4(pix_hi, mmk));
+                sss[3] = i64x2_add(sss[3], i64x2_extmul_high_i32x4(pix_hi, mmk));
+            }
+            let dst = dst_components.get_unchecked_mut(x..x + 8);
+            for (i, ss) in sss.iter().copied().enumerate() {
+                dst[i * 2] = normalizer.clip(i64x2_extract_lane::<0>(ss));
+                dst[i * 2 + 1] = normalizer.clip(i64x2_extract_lane::<1>(ss));
+            }
+            x += 8;
+        }
+
+        for (dst_x, dst_component) in dst_components.iter_mut().enumerate().skip(x) {
+            let mut ss = initial;
+            let src_rows = src_image.iter_rows(coeffs_chunk.start);
+            for (&k, src_row) in coeffs_chunk.values.iter().zip(src_rows) {
+                let src_component = *T::components(src_row).get_unchecked(src_x_initial + dst_x);
+                ss += src_component as i64 * (k as i64);
+            }
+            *dst_component = normalizer.clip(ss);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::arch::x86_64::*;
+
+use crate::convolution::vertical_u8::sse4;
+use crate::convolution::{optimisations, Coefficients};
+use crate::pixels::PixelExt;
+use crate::x86_64_utils;
+use crate::{ImageView, ImageViewMut};
+
+#[inline]
+pub(crate) fn vert_convolution<T: PixelExt<Component = u8>>(
+    src_image: &ImageView<T>,
+    dst_image: &mut ImageViewMut<T>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer = optimisations::Normalizer16::new(coeffs);
+    let coefficients_chunks = normalizer.normalized_chunks();
+    let src_x = offset as usize * T::count_of_components();
+
+    let dst_rows = dst_image.iter_rows_mut();
+    for (dst_row, coeffs_chunk) in dst_rows.zip(coefficients_chunks) {
+        unsafe {
+            vert_convolution_into_one_row_u8(src_image, dst_row, src_x, coeffs_chunk, &normalizer);
+        }
+    }
+}
+
+/// Every iteration of the main loop takes 32 components (8 pixels of `U8x4`)
+/// of the destination row, the rest of the row is handled by SSE4.1 kernel.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn vert_convolution_into_one_row_u8<T: PixelExt<Component = u8>>(
+    src_img: &ImageView<T>,
+    dst_row: &mut [T],
+    mut src_x: usize,
+    coeffs_chunk: optimisations::CoefficientsI16Chunk,
+    normalizer: &optimisations::Normalizer16,
+) {
+    unsafe {
+        let zero = _mm256_setzero_si256();
+        let y_start = coeffs_chunk.start;
+        let coeffs = coeffs_chunk.values;
+        let max_y = y_start + coeffs.len() as u32;
+        let precision = normalizer.precision();
+        let shift = _mm_cvtsi32_si128(precision as i32);
+        let dst_u8 = T::components_mut(dst_row);
+
+        let initial = _mm256_set1_epi32(1 << (precision - 1));
+
+        let mut dst_chunks_32 = dst_u8.chunks_exact_mut(32);
+        for dst_chunk in &mut dst_chunks_32 {
+            // Lower lanes contain components 0..16 and upper lanes contain components 16..32.
+            let mut sss = [initial; 4];
+            let mut y: u32 = 0;
+
+            for src_rows in src_img.iter_2_rows(y_start, max_y) {
+                let components1 = T::components(src_rows[0]);
+                let components2 = T::components(src_rows[1]);
+
+                // Load two coefficients at once
+                let mmk = x86_64_utils::ptr_i16_to_256set1_epi32(coeffs, y as usize);
+
+                let source1 = x86_64_utils::loadu_si256(components1, src_x); // top line
+                let source2 = x86_64_utils::loadu_si256(components2, src_x); // bottom line
+
+                let source = _mm256_unpacklo_epi8(source1, source2);
+                let pix = _mm256_unpacklo_epi8(source, zero);
+                sss[0] = _mm256_add_epi32(sss[0], _mm256_madd_epi16(pix, mmk));
+                let pix = _mm256_unpackhi_epi8(source, zero);
+                sss[1] = _mm256_add_epi32(sss[1], _mm256_madd_epi16(pix, mmk));
+
+                let source = _mm256_unpackhi_epi8(source1, source2);
+                let pix = _mm256_unpacklo_epi8(source, zero);
+                sss[2] = _mm256_add_epi32(sss[2], _mm256_madd_epi16(pix, mmk));
+                let pix = _mm256_unpackhi_epi8(source, zero);
+                sss[3] = _mm256_add_epi32(sss[3], _mm256_madd_epi16(pix, mmk));
+
+                y += 2;
+            }
+
+            if let Some(&k) = coeffs.get(y as usize) {
+                let s_row = src_img.get_row((y_start + y) as usize).unwrap();
+                let components = T::components(s_row);
+                let mmk = _mm256_set1_epi32(k as i32);
+
+                let source1 = x86_64_utils::loadu_si256(components, src_x); // top line
+
+                let source = _mm256_unpacklo_epi8(source1, zero);
+                let pix = _mm256_unpacklo_epi8(source, zero);
+                sss[0] = _mm256_add_epi32(sss[0], _mm256_madd_epi16(pix, mmk));
+                let pix = _mm256_unpackhi_epi8(source, zero);
+                sss[1] = _mm256_add_epi32(sss[1], _mm256_madd_epi16(pix, mmk));
+
+                let source = _mm256_unpackhi_epi8(source1, zero);
+                let pix = _mm256_unpacklo_epi8(source, zero);
+                sss[2] = _mm256_add_epi32(sss[2], _mm256_madd_epi16(pix, mmk));
+                let pix = _mm256_unpackhi_epi8(source, zero);
+                sss[3] = _mm256_add_epi32(sss[3], _mm256_madd_epi16(pix, mmk));
+            }
+
+            for s in sss.iter_mut() {
+                *s = _mm256_sra_epi32(*s, shift);
+            }
+            // Packing works within lanes, so the components stay in order.
+            let lo = _mm256_packs_epi32(sss[0], sss[1]);
+            let hi = _mm256_packs_epi32(sss[2], sss[3]);
+            let res = _mm256_packus_epi16(lo, hi);
+            _mm256_storeu_si256(dst_chunk.as_mut_ptr() as *mut __m256i, res);
+
+            src_x += 32;
+        }
+
+        let dst_u8 = dst_chunks_32.into_remainder();
+        if !dst_u8.is_empty() {
+            sse4::vert_convolution_into_one_row_u8(src_img, dst_u8, src_x, coeffs_chunk, normalizer);
+        }
+    }
+}
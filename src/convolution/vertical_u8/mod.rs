@@ -3,21 +3,28 @@ use crate::pixels::PixelExt;
 use crate::CpuExtensions;
 use crate::{ImageView, ImageViewMut};
 
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod avx2;
 pub(crate) mod native;
-#[cfg(target_arch = "wasm32")]
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod sse4;
+#[cfg(target_feature = "simd128")]
 pub(crate) mod wasm32;
 
 pub(crate) fn vert_convolution_u8<T: PixelExt<Component = u8>>(
     src_image: &ImageView<T>,
     dst_image: &mut ImageViewMut<T>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
     cpu_extensions: CpuExtensions,
 ) {
     match cpu_extensions {
-        #[cfg(target_arch = "wasm32")]
+        #[cfg(target_feature = "simd128")]
         CpuExtensions::Simd128 => wasm32::vert_convolution(src_image, dst_image, offset, coeffs),
-        #[cfg(not(target_arch = "wasm32"))]
+        #[cfg(target_arch = "x86_64")]
+        CpuExtensions::Avx2 => avx2::vert_convolution(src_image, dst_image, offset, coeffs),
+        #[cfg(target_arch = "x86_64")]
+        CpuExtensions::Sse4_1 => sse4::vert_convolution(src_image, dst_image, offset, coeffs),
         CpuExtensions::None => native::vert_convolution(src_image, dst_image, offset, coeffs),
     }
 }
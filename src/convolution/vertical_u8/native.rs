@@ -1,18 +1,15 @@
 use crate::convolution::optimisations;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::convolution::Coefficients;
-#[cfg(not(target_arch = "wasm32"))]
 use crate::image_view::ImageViewMut;
 use crate::pixels::PixelExt;
 use crate::ImageView;
 
-#[cfg(not(target_arch = "wasm32"))]
 #[inline(always)]
 pub(crate) fn vert_convolution<T>(
     src_image: &ImageView<T>,
     dst_image: &mut ImageViewMut<T>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) where
     T: PixelExt<Component = u8>,
 {
@@ -97,7 +94,6 @@ pub(crate) fn vert_convolution<T>(
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[inline(always)]
 fn convolution_by_chunks<T, const CHUNK_SIZE: usize>(
     src_image: &ImageView<T>,
@@ -140,7 +136,6 @@ fn convolution_by_chunks<T, const CHUNK_SIZE: usize>(
     x_src
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 #[inline(always)]
 pub(crate) fn foreach_with_pre_reading<D, I>(
     mut iter: impl Iterator<Item = I>,
@@ -1,18 +1,34 @@
-use crate::pixels::U8x4;
+use crate::pixels::{F32, F32x4, Rgb565, Rgba4444, U16x4, U8, U8x2, U8x3, U8x4};
 use crate::{ImageView, ImageViewMut};
 
 /// An immutable view of image data used by resizer as source image.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum DynamicImageView<'a> {
-    U8x4(ImageView<'a, U8x4>)
+    U8(ImageView<'a, U8>),
+    U8x2(ImageView<'a, U8x2>),
+    U8x3(ImageView<'a, U8x3>),
+    U8x4(ImageView<'a, U8x4>),
+    U16x4(ImageView<'a, U16x4>),
+    F32(ImageView<'a, F32>),
+    F32x4(ImageView<'a, F32x4>),
+    Rgb565(ImageView<'a, Rgb565>),
+    Rgba4444(ImageView<'a, Rgba4444>),
 }
 
 /// A mutable view of image data used by resizer as destination image.
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum DynamicImageViewMut<'a> {
-    U8x4(ImageViewMut<'a, U8x4>)
+    U8(ImageViewMut<'a, U8>),
+    U8x2(ImageViewMut<'a, U8x2>),
+    U8x3(ImageViewMut<'a, U8x3>),
+    U8x4(ImageViewMut<'a, U8x4>),
+    U16x4(ImageViewMut<'a, U16x4>),
+    F32(ImageViewMut<'a, F32>),
+    F32x4(ImageViewMut<'a, F32x4>),
+    Rgb565(ImageViewMut<'a, Rgb565>),
+    Rgba4444(ImageViewMut<'a, Rgba4444>),
 }
 
 macro_rules! from_typed {
@@ -31,13 +47,61 @@ macro_rules! from_typed {
     };
 }
 
+from_typed!(U8, DynamicImageView::U8, DynamicImageViewMut::U8);
+from_typed!(U8x2, DynamicImageView::U8x2, DynamicImageViewMut::U8x2);
+from_typed!(U8x3, DynamicImageView::U8x3, DynamicImageViewMut::U8x3);
 from_typed!(U8x4, DynamicImageView::U8x4, DynamicImageViewMut::U8x4);
+from_typed!(U16x4, DynamicImageView::U16x4, DynamicImageViewMut::U16x4);
+from_typed!(F32, DynamicImageView::F32, DynamicImageViewMut::F32);
+from_typed!(F32x4, DynamicImageView::F32x4, DynamicImageViewMut::F32x4);
+from_typed!(Rgb565, DynamicImageView::Rgb565, DynamicImageViewMut::Rgb565);
+from_typed!(Rgba4444, DynamicImageView::Rgba4444, DynamicImageViewMut::Rgba4444);
+
+impl DynamicImageViewMut<'_> {
+    pub fn width(&self) -> usize {
+        use DynamicImageViewMut::*;
+        match self {
+            U8(typed_view) => typed_view.width(),
+            U8x2(typed_view) => typed_view.width(),
+            U8x3(typed_view) => typed_view.width(),
+            U8x4(typed_view) => typed_view.width(),
+            U16x4(typed_view) => typed_view.width(),
+            F32(typed_view) => typed_view.width(),
+            F32x4(typed_view) => typed_view.width(),
+            Rgb565(typed_view) => typed_view.width(),
+            Rgba4444(typed_view) => typed_view.width(),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        use DynamicImageViewMut::*;
+        match self {
+            U8(typed_view) => typed_view.height(),
+            U8x2(typed_view) => typed_view.height(),
+            U8x3(typed_view) => typed_view.height(),
+            U8x4(typed_view) => typed_view.height(),
+            U16x4(typed_view) => typed_view.height(),
+            F32(typed_view) => typed_view.height(),
+            F32x4(typed_view) => typed_view.height(),
+            Rgb565(typed_view) => typed_view.height(),
+            Rgba4444(typed_view) => typed_view.height(),
+        }
+    }
+}
 
 impl<'a> From<DynamicImageViewMut<'a>> for DynamicImageView<'a> {
     fn from(dyn_view: DynamicImageViewMut<'a>) -> Self {
         use DynamicImageViewMut::*;
         match dyn_view {
-            U8x4(typed_view) => DynamicImageView::U8x4(typed_view.into())
+            U8(typed_view) => DynamicImageView::U8(typed_view.into()),
+            U8x2(typed_view) => DynamicImageView::U8x2(typed_view.into()),
+            U8x3(typed_view) => DynamicImageView::U8x3(typed_view.into()),
+            U8x4(typed_view) => DynamicImageView::U8x4(typed_view.into()),
+            U16x4(typed_view) => DynamicImageView::U16x4(typed_view.into()),
+            F32(typed_view) => DynamicImageView::F32(typed_view.into()),
+            F32x4(typed_view) => DynamicImageView::F32x4(typed_view.into()),
+            Rgb565(typed_view) => DynamicImageView::Rgb565(typed_view.into()),
+            Rgba4444(typed_view) => DynamicImageView::Rgba4444(typed_view.into()),
         }
     }
 }
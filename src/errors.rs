@@ -1,11 +1,178 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ImageBufferError {
     InvalidBufferSize,
     InvalidBufferAlignment,
 }
 
+impl Display for ImageBufferError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidBufferSize => write!(f, "Size of buffer doesn't match dimensions of image"),
+            Self::InvalidBufferAlignment => {
+                write!(f, "Buffer is not aligned to the alignment of pixel")
+            }
+        }
+    }
+}
+
+impl Error for ImageBufferError {}
+
 #[derive(Debug, Clone, Copy)]
 pub struct DifferentTypesOfPixelsError;
 
+impl Display for DifferentTypesOfPixelsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Source and destination images have different types of pixels")
+    }
+}
+
+impl Error for DifferentTypesOfPixelsError {}
+
 #[derive(Debug, Clone, Copy)]
 pub struct DifferentDimensionsError;
+
+impl Display for DifferentDimensionsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Source and destination images have different dimensions")
+    }
+}
+
+impl Error for DifferentDimensionsError {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UnknownFilterTypeError;
+
+impl Display for UnknownFilterTypeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown filter type")
+    }
+}
+
+impl Error for UnknownFilterTypeError {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UnknownPixelLayoutError;
+
+impl Display for UnknownPixelLayoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown pixel layout")
+    }
+}
+
+impl Error for UnknownPixelLayoutError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappingError {
+    UnsupportedCombinationOfImageTypes,
+    DifferentDimensions,
+}
+
+impl Display for MappingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedCombinationOfImageTypes => {
+                write!(f, "Unsupported combination of types of source and destination images")
+            }
+            Self::DifferentDimensions => DifferentDimensionsError.fmt(f),
+        }
+    }
+}
+
+impl Error for MappingError {}
+
+/// Error returned by the top-level resize functions, and thrown as an
+/// exception on the javascript side.
+///
+/// Every function fails if the width or the height of an image is zero or too big,
+/// or if the length of a buffer doesn't match the dimensions of its image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResizeError {
+    /// Width or height of an image is zero.
+    ZeroDimensions,
+    /// Size of an image buffer doesn't fit into `usize`.
+    SizeOverflow,
+    ImageBufferError(ImageBufferError),
+    DifferentTypesOfPixels,
+    DifferentDimensions,
+    /// No source buffer has been allocated before resizing it.
+    NoSourceBuffer,
+    /// Crop box is empty or isn't inside the source image.
+    CropBoxOutOfBounds,
+    /// Region of the destination image isn't inside the latter.
+    RegionOutOfBounds,
+    UnknownFilterType,
+    /// Multiplicity of supersampling is zero.
+    ZeroMultiplicity,
+    UnknownPixelLayout,
+    /// Chroma planes don't have half the dimensions of luma plane, rounded up.
+    InvalidChromaDimensions,
+    /// CPU extensions aren't supported by the current CPU.
+    UnsupportedCpuExtensions,
+}
+
+impl Display for ResizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ZeroDimensions => write!(f, "Width and height of image must be greater than zero"),
+            Self::SizeOverflow => write!(f, "Size of image buffer is too big"),
+            Self::ImageBufferError(e) => e.fmt(f),
+            Self::DifferentTypesOfPixels => DifferentTypesOfPixelsError.fmt(f),
+            Self::DifferentDimensions => DifferentDimensionsError.fmt(f),
+            Self::NoSourceBuffer => write!(f, "Source buffer has not been allocated"),
+            Self::CropBoxOutOfBounds => write!(f, "Crop box is outside of the source image"),
+            Self::RegionOutOfBounds => write!(f, "Region is outside of the destination image"),
+            Self::UnknownFilterType => UnknownFilterTypeError.fmt(f),
+            Self::ZeroMultiplicity => write!(f, "Multiplicity of supersampling must be greater than zero"),
+            Self::UnknownPixelLayout => UnknownPixelLayoutError.fmt(f),
+            Self::InvalidChromaDimensions => {
+                write!(f, "Dimensions of chroma planes don't match dimensions of luma plane")
+            }
+            Self::UnsupportedCpuExtensions => {
+                write!(f, "CPU extensions are not supported by the current CPU")
+            }
+        }
+    }
+}
+
+impl Error for ResizeError {}
+
+impl From<ImageBufferError> for ResizeError {
+    fn from(e: ImageBufferError) -> Self {
+        Self::ImageBufferError(e)
+    }
+}
+
+impl From<DifferentTypesOfPixelsError> for ResizeError {
+    fn from(_: DifferentTypesOfPixelsError) -> Self {
+        Self::DifferentTypesOfPixels
+    }
+}
+
+impl From<DifferentDimensionsError> for ResizeError {
+    fn from(_: DifferentDimensionsError) -> Self {
+        Self::DifferentDimensions
+    }
+}
+
+impl From<UnknownFilterTypeError> for ResizeError {
+    fn from(_: UnknownFilterTypeError) -> Self {
+        Self::UnknownFilterType
+    }
+}
+
+impl From<UnknownPixelLayoutError> for ResizeError {
+    fn from(_: UnknownPixelLayoutError) -> Self {
+        Self::UnknownPixelLayout
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl From<ResizeError> for wasm_bindgen::JsValue {
+    fn from(e: ResizeError) -> Self {
+        wasm_bindgen::JsError::from(e).into()
+    }
+}
@@ -67,7 +67,7 @@ impl Image {
     }
 
     #[inline(always)]
-    pub fn view(&self) -> DynamicImageView {
+    pub fn view(&self) -> DynamicImageView<'_> {
         macro_rules! get_dynamic_image {
             ($img_type: expr) => {
                 ($img_type(ImageView::from_buffer(self.width, self.height, self.buffer()).unwrap()))
@@ -75,12 +75,20 @@ impl Image {
         }
 
         match self.pixel_type {
+            PixelType::U8 => get_dynamic_image!(DynamicImageView::U8),
+            PixelType::U8x2 => get_dynamic_image!(DynamicImageView::U8x2),
+            PixelType::U8x3 => get_dynamic_image!(DynamicImageView::U8x3),
             PixelType::U8x4 => get_dynamic_image!(DynamicImageView::U8x4),
+            PixelType::U16x4 => get_dynamic_image!(DynamicImageView::U16x4),
+            PixelType::F32 => get_dynamic_image!(DynamicImageView::F32),
+            PixelType::F32x4 => get_dynamic_image!(DynamicImageView::F32x4),
+            PixelType::Rgb565 => get_dynamic_image!(DynamicImageView::Rgb565),
+            PixelType::Rgba4444 => get_dynamic_image!(DynamicImageView::Rgba4444),
         }
     }
 
     #[inline(always)]
-    pub fn view_mut(&mut self) -> DynamicImageViewMut {
+    pub fn view_mut(&mut self) -> DynamicImageViewMut<'_> {
         macro_rules! get_dynamic_image {
             ($img_type: expr) => {
                 ($img_type(
@@ -90,7 +98,15 @@ impl Image {
         }
 
         match self.pixel_type {
+            PixelType::U8 => get_dynamic_image!(DynamicImageViewMut::U8),
+            PixelType::U8x2 => get_dynamic_image!(DynamicImageViewMut::U8x2),
+            PixelType::U8x3 => get_dynamic_image!(DynamicImageViewMut::U8x3),
             PixelType::U8x4 => get_dynamic_image!(DynamicImageViewMut::U8x4),
+            PixelType::U16x4 => get_dynamic_image!(DynamicImageViewMut::U16x4),
+            PixelType::F32 => get_dynamic_image!(DynamicImageViewMut::F32),
+            PixelType::F32x4 => get_dynamic_image!(DynamicImageViewMut::F32x4),
+            PixelType::Rgb565 => get_dynamic_image!(DynamicImageViewMut::Rgb565),
+            PixelType::Rgba4444 => get_dynamic_image!(DynamicImageViewMut::Rgba4444),
         }
     }
 }
@@ -118,7 +134,7 @@ where
     }
 
     #[inline(always)]
-    pub fn dst_view(&mut self) -> ImageViewMut<P> {
+    pub fn dst_view(&mut self) -> ImageViewMut<'_, P> {
         ImageViewMut::from_pixels(self.width, self.height, self.pixels).unwrap()
     }
 }
@@ -43,6 +43,28 @@ where
         })
     }
 
+    /// Creates a view of an image whose rows start every `stride` bytes
+    /// of the buffer (e.g. a plane of a video frame with padded rows).
+    pub fn from_buffer_with_stride(
+        width: usize,
+        height: usize,
+        buffer: &'a [u8],
+        stride: usize,
+    ) -> Result<Self, ImageBufferError> {
+        let stride = stride_in_pixels::<P>(width, height, buffer.len(), stride)?;
+        let pixels = align_buffer_to(buffer)?;
+        let rows = pixels
+            .chunks(stride)
+            .take(height)
+            .map(|row| &row[..width])
+            .collect();
+        Ok(Self {
+            width,
+            height,
+            rows,
+        })
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -51,7 +73,7 @@ where
         self.height
     }
 
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(any(target_feature = "simd128", target_arch = "x86_64"))]
     #[inline(always)]
     pub(crate) fn iter_4_rows<'s>(
         &'s self,
@@ -67,7 +89,7 @@ where
         })
     }
 
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(any(target_feature = "simd128", target_arch = "x86_64"))]
     #[inline(always)]
     pub(crate) fn iter_2_rows<'s>(
         &'s self,
@@ -90,11 +112,27 @@ where
         rows.iter().copied()
     }
 
-    #[cfg(target_arch = "wasm32")]
     #[inline(always)]
     pub(crate) fn get_row(&self, y: usize) -> Option<&'a [P]> {
         self.rows.get(y).copied()
     }
+
+    /// Inserts `count` empty rows before the rows of the view, so that the
+    /// indexes of the latter are shifted by `count`.
+    /// The empty rows must never be read.
+    pub(crate) fn with_empty_rows_before(mut self, count: usize) -> Self {
+        self.rows.splice(0..0, std::iter::repeat_n(&[][..], count));
+        self.height += count;
+        self
+    }
+
+    /// Appends `count` empty rows after the rows of the view.
+    /// The empty rows must never be read.
+    pub(crate) fn with_empty_rows_after(mut self, count: usize) -> Self {
+        self.rows.extend(std::iter::repeat_n(&[][..], count));
+        self.height += count;
+        self
+    }
 }
 
 /// Generic mutable image view.
@@ -135,6 +173,28 @@ where
         })
     }
 
+    /// Creates a view of an image whose rows start every `stride` bytes
+    /// of the buffer (e.g. a plane of a video frame with padded rows).
+    pub fn from_buffer_with_stride(
+        width: usize,
+        height: usize,
+        buffer: &'a mut [u8],
+        stride: usize,
+    ) -> Result<Self, ImageBufferError> {
+        let stride = stride_in_pixels::<P>(width, height, buffer.len(), stride)?;
+        let pixels = align_buffer_to_mut(buffer)?;
+        let rows = pixels
+            .chunks_mut(stride)
+            .take(height)
+            .map(|row| &mut row[..width])
+            .collect();
+        Ok(Self {
+            width,
+            height,
+            rows,
+        })
+    }
+
     pub fn from_pixels(
         width: usize,
         height: usize,
@@ -165,11 +225,35 @@ where
     }
 
     #[inline(always)]
-    pub(crate) fn iter_rows_mut(&mut self) -> slice::IterMut<&'a mut [P]> {
+    pub(crate) fn iter_rows_mut(&mut self) -> slice::IterMut<'_, &'a mut [P]> {
         self.rows.iter_mut()
     }
 
-    #[cfg(target_arch = "wasm32")]
+    /// Splits the view into views of consecutive bands of rows with given heights.
+    pub(crate) fn split_by_heights<'s>(
+        &'s mut self,
+        heights: impl IntoIterator<Item = usize>,
+    ) -> Vec<ImageViewMut<'s, P>> {
+        let width = self.width;
+        let mut rows = self.rows.iter_mut();
+        heights
+            .into_iter()
+            .map(|height| ImageViewMut {
+                width,
+                height,
+                rows: rows.by_ref().take(height).map(|row| &mut **row).collect(),
+            })
+            .collect()
+    }
+
+    /// Copies pixels of source image with the same dimensions.
+    pub(crate) fn copy_from_view(&mut self, src_image: &ImageView<P>) {
+        for (src_row, dst_row) in src_image.iter_rows(0).zip(self.iter_rows_mut()) {
+            dst_row.copy_from_slice(src_row);
+        }
+    }
+
+    #[cfg(any(target_feature = "simd128", target_arch = "x86_64"))]
     #[inline(always)]
     pub(crate) fn iter_4_rows_mut<'s>(
         &'s mut self,
@@ -180,7 +264,7 @@ where
         })
     }
 
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(any(target_feature = "simd128", target_arch = "x86_64"))]
     #[inline(always)]
     pub(crate) fn get_row_mut<'s>(&'s mut self, y: usize) -> Option<&'s mut &'a mut [P]> {
         self.rows.get_mut(y)
@@ -207,6 +291,30 @@ where
     }
 }
 
+/// Checks that rows with given stride (in bytes) fit into the buffer
+/// and returns the stride in pixels.
+fn stride_in_pixels<P: PixelExt>(
+    width: usize,
+    height: usize,
+    buffer_len: usize,
+    stride: usize,
+) -> Result<usize, ImageBufferError> {
+    if !stride.is_multiple_of(P::size()) {
+        return Err(ImageBufferError::InvalidBufferAlignment);
+    }
+    let row_size = width * P::size();
+    let size = match height {
+        0 => Some(0),
+        _ => stride
+            .checked_mul(height - 1)
+            .and_then(|size| size.checked_add(row_size)),
+    };
+    match size {
+        Some(size) if stride >= row_size && size <= buffer_len => Ok((stride / P::size()).max(1)),
+        _ => Err(ImageBufferError::InvalidBufferSize),
+    }
+}
+
 fn align_buffer_to<T>(buffer: &[u8]) -> Result<&[T], ImageBufferError> {
     let (head, pixels, _) = unsafe { buffer.align_to::<T>() };
     if !head.is_empty() {
@@ -1,72 +1,205 @@
-#![allow(dead_code)]
-#![allow(warnings)]
-
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-unsafe extern "C" {
-    #[wasm_bindgen(js_namespace = console)]
-    fn log(s: &str);
+/// Resizes the supplied rgba buffer with the named algorithm (see [ResizeAlg::from_str]),
+/// in linear light if `linear_light` is set.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn resize(rgba: &[u8], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str, linear_light: bool) -> Result<Vec<u8>, ResizeError> {
+    let mut resizer = Resizer::with_algorithm(algorithm)?;
+    resizer.set_linear_light(linear_light);
+    resizer.resize_rgba(rgba, input_width, input_height, output_width, output_height)
 }
-#[cfg(not(target_arch = "wasm32"))]
-fn log(s: &str) {
-    println!("{s}");
+
+/// Resizes the supplied rgba buffer into the supplied destination buffer
+/// with the named algorithm, in linear light if `linear_light` is set.
+///
+/// Nothing is allocated for the result.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeInto))]
+#[allow(clippy::too_many_arguments)]
+pub fn resize_into(src: &[u8], input_width: usize, input_height: usize, dst: &mut [u8], output_width: usize, output_height: usize, algorithm: &str, linear_light: bool) -> Result<(), ResizeError> {
+    let mut resizer = Resizer::with_algorithm(algorithm)?;
+    resizer.set_linear_light(linear_light);
+    resizer.resize_rgba_into(src, input_width, input_height, dst, output_width, output_height)
 }
 
-#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
-pub fn resize(rgba: &[u8], input_width: usize, input_height: usize, output_width: usize, output_height: usize, hq: bool) -> Vec<u8> {
-    let src = Image::from_vec_u8(
-        input_width,
-        input_height,
-        rgba.to_vec(),
-        PixelType::U8x4
-    )
-        .map_err(|e| log(format!("{e:?}").as_str()))
-        .unwrap();
-    let mut dest = Image::new(
-        output_width,
-        output_height,
-        PixelType::U8x4
-    );
-    let mut resizer = Resizer::new(ResizeAlg::Convolution(if hq { FilterType::CatmullRom } else { FilterType::Hamming }));
-    resizer.resize(&src.view(), &mut dest.view_mut())
-        .map_err(|e| log(format!("{e:?}").as_str()))
-        .unwrap();
-    dest.buffer().to_vec()
+/// Resizes the supplied rgba buffer with the named algorithm, in linear light
+/// if `linear_light` is set, but computes only the region of the result with
+/// dimensions `width` x `height` which starts at `left` x `top`.
+///
+/// The region is equal to the matching region of the whole result, so that
+/// a resize can be split between several workers, or only a part of an image
+/// can be updated.
+/// Returns an error if the region is outside of the result.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRegion))]
+#[allow(clippy::too_many_arguments)]
+pub fn resize_region(rgba: &[u8], input_width: usize, input_height: usize, output_width: usize, output_height: usize, left: usize, top: usize, width: usize, height: usize, algorithm: &str, linear_light: bool) -> Result<Vec<u8>, ResizeError> {
+    let mut resizer = Resizer::with_algorithm(algorithm)?;
+    resizer.set_linear_light(linear_light);
+    resizer.resize_rgba_region(rgba, input_width, input_height, output_width, output_height, left, top, width, height)
+}
+
+/// Resizes the supplied rgba buffer with 16 bits per channel with the named algorithm.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgbaU16))]
+pub fn resize_rgba_u16(rgba: &[u16], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str) -> Result<Vec<u16>, ResizeError> {
+    Resizer::with_algorithm(algorithm)?.resize_rgba_u16(rgba, input_width, input_height, output_width, output_height)
+}
+
+/// Resizes the supplied RGB565 buffer (one u16 per pixel) with the named algorithm,
+/// with ordered dithering if `dithering` is set.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgb565))]
+pub fn resize_rgb565(rgb565: &[u16], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str, dithering: bool) -> Result<Vec<u16>, ResizeError> {
+    let mut resizer = Resizer::with_algorithm(algorithm)?;
+    resizer.set_dithering(dithering);
+    resizer.resize_rgb565(rgb565, input_width, input_height, output_width, output_height)
+}
+
+/// Resizes the supplied RGBA4444 buffer (one u16 per pixel) with the named algorithm,
+/// with ordered dithering of the color channels if `dithering` is set.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgba4444))]
+pub fn resize_rgba4444(rgba4444: &[u16], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str, dithering: bool) -> Result<Vec<u16>, ResizeError> {
+    let mut resizer = Resizer::with_algorithm(algorithm)?;
+    resizer.set_dithering(dithering);
+    resizer.resize_rgba4444(rgba4444, input_width, input_height, output_width, output_height)
+}
+
+/// Resizes the supplied Y, U and V planes of an I420 image, each with its own
+/// stride in bytes, with the named algorithm and returns the resized planes
+/// one after another without padding.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeI420))]
+#[allow(clippy::too_many_arguments)]
+pub fn resize_i420(y: &[u8], y_stride: usize, u: &[u8], u_stride: usize, v: &[u8], v_stride: usize, input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str) -> Result<Vec<u8>, ResizeError> {
+    Resizer::with_algorithm(algorithm)?.resize_i420(y, y_stride, u, u_stride, v, v_stride, input_width, input_height, output_width, output_height)
+}
+
+/// Resizes the supplied Y and interleaved UV planes of an NV12 image, each with
+/// its own stride in bytes, with the named algorithm and returns the resized
+/// planes one after another without padding.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeNv12))]
+#[allow(clippy::too_many_arguments)]
+pub fn resize_nv12(y: &[u8], y_stride: usize, uv: &[u8], uv_stride: usize, input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str) -> Result<Vec<u8>, ResizeError> {
+    Resizer::with_algorithm(algorithm)?.resize_nv12(y, y_stride, uv, uv_stride, input_width, input_height, output_width, output_height)
+}
+
+/// Resizes the supplied grayscale buffer of floats (one per pixel) with the named algorithm.
+/// Values are neither clamped nor rounded.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeGrayF32))]
+pub fn resize_gray_f32(gray: &[f32], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str) -> Result<Vec<f32>, ResizeError> {
+    Resizer::with_algorithm(algorithm)?.resize_gray_f32(gray, input_width, input_height, output_width, output_height)
+}
+
+/// Resizes the supplied rgba buffer of floats (four per pixel) with the named algorithm.
+/// Values are neither clamped nor rounded.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgbaF32))]
+pub fn resize_rgba_f32(rgba: &[f32], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str) -> Result<Vec<f32>, ResizeError> {
+    Resizer::with_algorithm(algorithm)?.resize_rgba_f32(rgba, input_width, input_height, output_width, output_height)
+}
+
+/// Resizes the supplied packed rgb buffer (three bytes per pixel) with the named algorithm.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgb))]
+pub fn resize_rgb(rgb: &[u8], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str) -> Result<Vec<u8>, ResizeError> {
+    Resizer::with_algorithm(algorithm)?.resize_rgb(rgb, input_width, input_height, output_width, output_height)
 }
 
-use convolution::FilterType;
-use dynamic_image_view::{
+/// Resizes the supplied grayscale buffer (one byte per pixel) with the named algorithm.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeGray))]
+pub fn resize_gray(gray: &[u8], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str) -> Result<Vec<u8>, ResizeError> {
+    Resizer::with_algorithm(algorithm)?.resize_gray(gray, input_width, input_height, output_width, output_height)
+}
+
+/// Resizes the supplied grayscale buffer with alpha channel (two bytes per pixel,
+/// luma then alpha) with the named algorithm.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeGrayAlpha))]
+pub fn resize_gray_alpha(gray_alpha: &[u8], input_width: usize, input_height: usize, output_width: usize, output_height: usize, algorithm: &str) -> Result<Vec<u8>, ResizeError> {
+    Resizer::with_algorithm(algorithm)?.resize_gray_alpha(gray_alpha, input_width, input_height, output_width, output_height)
+}
+
+/// Multiplies the rgb channels of the supplied rgba buffer by its alpha channel, in place.
+/// `layout` names the order of the channels (see [PixelLayout::from_str]).
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = multiplyAlpha))]
+pub fn multiply_alpha(rgba: &mut [u8], width: usize, height: usize, layout: &str) -> Result<(), ResizeError> {
+    let mut image = rgba_view_mut(rgba, width, height)?;
+    mul_div_with_layout(layout)?.multiply_alpha_inplace(&mut image)
+}
+
+/// Divides the rgb channels of the supplied rgba buffer by its alpha channel, in place.
+/// `layout` names the order of the channels (see [PixelLayout::from_str]).
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = divideAlpha))]
+pub fn divide_alpha(rgba: &mut [u8], width: usize, height: usize, layout: &str) -> Result<(), ResizeError> {
+    let mut image = rgba_view_mut(rgba, width, height)?;
+    mul_div_with_layout(layout)?.divide_alpha_inplace(&mut image)
+}
+
+/// Returns the name of the CPU extensions used by the other functions
+/// (see [CpuExtensions::name]), e.g. `simd128`, or `none` for the scalar build
+/// used by engines without SIMD support.
+///
+/// It only reports the best extensions available to this module: the functions
+/// always use them, and only [Resizer::set_cpu_extensions] can select others.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = cpuExtensions))]
+pub fn cpu_extensions() -> String {
+    CpuExtensions::default().name().to_string()
+}
+
+fn mul_div_with_layout(layout: &str) -> Result<MulDiv, ResizeError> {
+    let mut mul_div = MulDiv::default();
+    mul_div.set_pixel_layout(layout.parse()?);
+    Ok(mul_div)
+}
+
+fn rgba_view_mut(rgba: &mut [u8], width: usize, height: usize) -> Result<DynamicImageViewMut<'_>, ResizeError> {
+    if rgba.len() != PixelType::U8x4.buffer_size(width, height)? {
+        return Err(ImageBufferError::InvalidBufferSize.into());
+    }
+    Ok(ImageViewMut::<U8x4>::from_buffer(width, height, rgba)?.into())
+}
+
+pub use convolution::FilterType;
+pub use dynamic_image_view::{
     DynamicImageView, DynamicImageViewMut,
 };
-use errors::*;
-use image_view::{ImageView, ImageViewMut};
-use pixels::PixelType;
-use resizer::{CpuExtensions, ResizeAlg, Resizer};
+pub use errors::*;
+pub use image_view::{ImageView, ImageViewMut};
+pub use mul_div::MulDiv;
+pub use pixels::{F32, F32x4, PixelLayout, PixelType, Rgb565, Rgba4444, U16x4, U8, U8x2, U8x3, U8x4};
+pub use resizer::{CpuExtensions, CropBox, ResizeAlg, Resizer};
 
-use crate::image::Image;
+pub use crate::image::Image;
 
 mod alpha;
+pub mod color;
 mod convolution;
 mod dynamic_image_view;
 mod errors;
 mod image;
 mod image_view;
+mod mul_div;
+mod nearest;
+mod packed;
 mod pixels;
 mod resizer;
-#[cfg(target_arch = "wasm32")]
+#[cfg(target_feature = "simd128")]
 mod wasm32_utils;
+#[cfg(target_arch = "x86_64")]
+mod x86_64_utils;
+
+#[cfg(test)]
+mod test_utils;
 
 #[cfg(test)]
 mod tests {
-    use image::ImageReader;
-    use crate::resize;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+    use crate::test_utils::{gradient, noise, opaque_gradient, region_of};
+    use crate::{
+        cpu_extensions, divide_alpha, multiply_alpha, resize, resize_gray, resize_gray_alpha, resize_gray_f32, resize_i420,
+        resize_into, resize_nv12, resize_region, resize_rgb, resize_rgb565, resize_rgba4444, resize_rgba_f32, resize_rgba_u16,
+        CpuExtensions, ImageBufferError, ResizeError,
+    };
 
+    #[cfg(not(target_arch = "wasm32"))]
     #[test]
     fn test_resize_bee_jpg() {
-        let rgb_image = ImageReader::open("bee.jpg").unwrap().decode().unwrap();
+        let rgb_image = ::image::ImageReader::open("bee.jpg").unwrap().decode().unwrap();
         let input_width = rgb_image.width() as usize;
         let input_height = rgb_image.height() as usize;
         assert_eq!(input_width, 2960);
@@ -75,7 +208,320 @@ mod tests {
         let data = rgba_image.to_vec();
         let output_width = 2074;
         let output_height = 1440;
-        resize(data.as_slice(), input_width, input_height, output_width, output_height, false);
-        resize(data.as_slice(), input_width, input_height, output_width, output_height, true);
+        resize(data.as_slice(), input_width, input_height, output_width, output_height, "hamming", false).unwrap();
+        resize(data.as_slice(), input_width, input_height, output_width, output_height, "catmullrom", false).unwrap();
+    }
+
+    #[test]
+    fn test_resize_errors() {
+        let data = vec![0u8; 4 * 4 * 4];
+        assert_eq!(
+            resize(&data[1..], 4, 4, 2, 2, "catmullrom", false),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferSize))
+        );
+        assert_eq!(resize(&data, 4, 4, 0, 2, "catmullrom", false), Err(ResizeError::ZeroDimensions));
+        assert_eq!(resize(&[], 0, 4, 2, 2, "catmullrom", false), Err(ResizeError::ZeroDimensions));
+        assert_eq!(
+            resize(&data, 4, 4, usize::MAX / 2, 2, "catmullrom", false),
+            Err(ResizeError::SizeOverflow)
+        );
+        assert_eq!(resize(&data, 4, 4, 2, 2, "catmullrom", false).unwrap().len(), 2 * 2 * 4);
+    }
+
+    #[test]
+    fn test_resize_into() {
+        let data = gradient(64 * 48 * 4);
+        let mut dst = vec![0; 20 * 15 * 4];
+        resize_into(&data, 64, 48, &mut dst, 20, 15, "catmullrom", false).unwrap();
+        assert_eq!(dst, resize(&data, 64, 48, 20, 15, "catmullrom", false).unwrap());
+        assert_eq!(
+            resize_into(&data, 64, 48, &mut dst, 20, 16, "catmullrom", false),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferSize))
+        );
+    }
+
+    #[test]
+    fn test_cpu_extensions() {
+        assert_eq!(cpu_extensions(), CpuExtensions::default().name());
+        #[cfg(target_feature = "simd128")]
+        assert_eq!(cpu_extensions(), "simd128");
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let mut data = [255, 128, 0, 128, 10, 20, 30, 0, 40, 50, 60, 255];
+        multiply_alpha(&mut data, 3, 1, "rgba").unwrap();
+        assert_eq!(data, [128, 64, 0, 128, 0, 0, 0, 0, 40, 50, 60, 255]);
+        divide_alpha(&mut data, 3, 1, "rgba").unwrap();
+        assert_eq!(data, [255, 127, 0, 128, 0, 0, 0, 0, 40, 50, 60, 255]);
+        assert!(multiply_alpha(&mut data, 2, 2, "rgba").is_err());
+        assert_eq!(multiply_alpha(&mut data, 3, 1, "rgb"), Err(ResizeError::UnknownPixelLayout));
+
+        let mut data = [128, 255, 128, 0, 0, 10, 20, 30, 255, 40, 50, 60];
+        multiply_alpha(&mut data, 3, 1, "ARGB").unwrap();
+        assert_eq!(data, [128, 128, 64, 0, 0, 0, 0, 0, 255, 40, 50, 60]);
+        divide_alpha(&mut data, 3, 1, "argb").unwrap();
+        assert_eq!(data, [128, 255, 127, 0, 0, 0, 0, 0, 255, 40, 50, 60]);
+    }
+
+    #[test]
+    fn test_resize_gray() {
+        let gray = gradient(64 * 48);
+        let rgba: Vec<u8> = gray.iter().flat_map(|&v| [v, v, v, 255]).collect();
+        for algorithm in ["nearest", "bilinear", "lanczos3", "supersampling:catmullrom:2"] {
+            let resized = resize_gray(&gray, 64, 48, 21, 13, algorithm).unwrap();
+            let expected: Vec<u8> = resize(&rgba, 64, 48, 21, 13, algorithm, false)
+                .unwrap()
+                .chunks_exact(4)
+                .map(|pixel| pixel[0])
+                .collect();
+            assert_eq!(resized, expected);
+        }
+        assert_eq!(
+            resize_gray(&gray, 64, 47, 21, 13, "box"),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferSize))
+        );
+    }
+
+    #[test]
+    fn test_resize_gray_alpha() {
+        let gray_alpha = gradient(64 * 48 * 2);
+        let rgba: Vec<u8> = gray_alpha
+            .chunks_exact(2)
+            .flat_map(|pixel| [pixel[0], pixel[0], pixel[0], pixel[1]])
+            .collect();
+        for algorithm in ["nearest", "bilinear", "lanczos3"] {
+            let resized = resize_gray_alpha(&gray_alpha, 64, 48, 21, 13, algorithm).unwrap();
+            let expected: Vec<u8> = resize(&rgba, 64, 48, 21, 13, algorithm, false)
+                .unwrap()
+                .chunks_exact(4)
+                .flat_map(|pixel| [pixel[0], pixel[3]])
+                .collect();
+            assert_eq!(resized, expected);
+        }
+    }
+
+    #[test]
+    fn test_resize_rgb() {
+        let rgb = gradient(64 * 48 * 3);
+        let rgba: Vec<u8> = rgb
+            .chunks_exact(3)
+            .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], 255])
+            .collect();
+        for algorithm in ["nearest", "bilinear", "lanczos3", "supersampling:catmullrom:2"] {
+            let resized = resize_rgb(&rgb, 64, 48, 21, 13, algorithm).unwrap();
+            let expected: Vec<u8> = resize(&rgba, 64, 48, 21, 13, algorithm, false)
+                .unwrap()
+                .chunks_exact(4)
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+                .collect();
+            assert_eq!(resized, expected);
+        }
+        assert_eq!(
+            resize_rgb(&rgb, 64, 47, 21, 13, "lanczos3"),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferSize))
+        );
+    }
+
+    #[test]
+    fn test_resize_rgba_u16() {
+        // Opaque, so that premultiplication in 8 bits doesn't lose precision.
+        let rgba = opaque_gradient(64 * 48 * 4);
+        let rgba_u16: Vec<u16> = rgba.iter().map(|&c| c as u16 * 257).collect();
+        for algorithm in ["nearest", "bilinear", "lanczos3"] {
+            let resized = resize_rgba_u16(&rgba_u16, 64, 48, 21, 13, algorithm).unwrap();
+            let expected = resize(&rgba, 64, 48, 21, 13, algorithm, false).unwrap();
+            assert_eq!(resized.len(), expected.len());
+            for (&c16, &c8) in resized.iter().zip(&expected) {
+                assert!((c16 as i32 - c8 as i32 * 257).abs() <= 257, "{c16} {c8}");
+            }
+        }
+
+        // Values don't lose precision.
+        let rgba_u16: Vec<u16> = [1000, 2001, 65535, 65535].repeat(4);
+        let resized = resize_rgba_u16(&rgba_u16, 2, 2, 1, 1, "lanczos3").unwrap();
+        assert_eq!(resized, [1000, 2001, 65535, 65535]);
+        assert_eq!(
+            resize_rgba_u16(&rgba_u16, 2, 1, 1, 1, "lanczos3"),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferSize))
+        );
+    }
+
+    #[test]
+    fn test_resize_f32() {
+        let gray = gradient(64 * 48);
+        let gray_f32: Vec<f32> = gray.iter().map(|&c| c as f32 / 255.).collect();
+        for algorithm in ["nearest", "bilinear", "supersampling:box:2"] {
+            let resized = resize_gray_f32(&gray_f32, 64, 48, 21, 13, algorithm).unwrap();
+            let expected = resize_gray(&gray, 64, 48, 21, 13, algorithm).unwrap();
+            for (&f, &c) in resized.iter().zip(&expected) {
+                assert!((f * 255. - c as f32).abs() <= 1., "{f} {c}");
+            }
+        }
+
+        // Values are neither clamped to [0, 1] nor rounded.
+        let step: Vec<f32> = [0., 0., 0., 1000., 1000., 1000.].repeat(2);
+        let resized = resize_gray_f32(&step, 6, 2, 4, 1, "lanczos3").unwrap();
+        assert!(resized[0] < 0. && resized[3] > 1000., "{resized:?}");
+        let resized = resize_gray_f32(&[0.25, 0.5], 2, 1, 1, 1, "box").unwrap();
+        assert_eq!(resized, [0.375]);
+
+        // Transparent pixels don't bleed into the visible ones.
+        let rgba = [2., 0.5, 0., 1., 0., 0., 1., 0.];
+        let resized = resize_rgba_f32(&rgba, 2, 1, 1, 1, "box").unwrap();
+        assert_eq!(resized, [2., 0.5, 0., 0.5]);
+        assert_eq!(
+            resize_rgba_f32(&rgba, 1, 1, 1, 1, "box"),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferSize))
+        );
+    }
+
+    #[test]
+    fn test_resize_packed() {
+        let rgb = gradient(64 * 48 * 3);
+        // Expanded 5 and 6 bit values, which are packed back without loss.
+        let expand = |v: u8, bits: u32| ((v >> (8 - bits)) << (8 - bits)) | (v >> (2 * bits - 8));
+        let rgb: Vec<u8> = rgb
+            .chunks_exact(3)
+            .flat_map(|p| [expand(p[0], 5), expand(p[1], 6), expand(p[2], 5)])
+            .collect();
+        let rgb565: Vec<u16> = rgb
+            .chunks_exact(3)
+            .map(|p| (p[0] as u16 >> 3) << 11 | (p[1] as u16 >> 2) << 5 | p[2] as u16 >> 3)
+            .collect();
+        for algorithm in ["nearest", "bilinear", "lanczos3", "supersampling:catmullrom:2"] {
+            let resized = resize_rgb565(&rgb565, 64, 48, 21, 13, algorithm, false).unwrap();
+            let expected = resize_rgb(&rgb, 64, 48, 21, 13, algorithm).unwrap();
+            for (packed, p) in resized.iter().zip(expected.chunks_exact(3)) {
+                let unpacked = [packed >> 11, (packed >> 5) & 0x3f, packed & 0x1f];
+                let rounded = [
+                    (p[0] as u16 * 31 + 127) / 255,
+                    (p[1] as u16 * 63 + 127) / 255,
+                    (p[2] as u16 * 31 + 127) / 255,
+                ];
+                for (a, b) in unpacked.iter().zip(rounded) {
+                    assert!(a.abs_diff(b) <= 1, "{algorithm}: {unpacked:?} {rounded:?}");
+                }
+            }
+        }
+        // Flat areas are kept as is by dithering, and gradients are dithered.
+        let flat = vec![0x7bef_u16; 16 * 16];
+        assert_eq!(resize_rgb565(&flat, 16, 16, 9, 7, "lanczos3", true).unwrap(), vec![0x7bef; 9 * 7]);
+        let gradient: Vec<u16> = (0..64 * 8).map(|i| ((i % 64) / 2) << 11).collect();
+        let dithered = resize_rgb565(&gradient, 64, 8, 48, 8, "bilinear", true).unwrap();
+        let rounded = resize_rgb565(&gradient, 64, 8, 48, 8, "bilinear", false).unwrap();
+        assert_ne!(dithered, rounded);
+        // Unlike rounding, dithering keeps the mean value of the gradient.
+        let sum = |v: &[u16]| v.iter().map(|&p| (p >> 11) as u32).sum::<u32>();
+        assert!(sum(&dithered).abs_diff(sum(&gradient) * 3 / 4) < 8);
+
+        let rgba4444: Vec<u16> = (0..64 * 48_u32).map(|i| i.wrapping_mul(2654435761) as u16).collect();
+        let rgba: Vec<u8> = rgba4444
+            .iter()
+            .flat_map(|&p| [p >> 12, (p >> 8) & 0xf, (p >> 4) & 0xf, p & 0xf].map(|c| c as u8 * 17))
+            .collect();
+        let resized = resize_rgba4444(&rgba4444, 64, 48, 21, 13, "lanczos3", false).unwrap();
+        let expected = resize(&rgba, 64, 48, 21, 13, "lanczos3", false).unwrap();
+        for (packed, p) in resized.iter().zip(expected.chunks_exact(4)) {
+            let rounded = p.iter().fold(0, |acc, &c| (acc << 4) | ((c as u16 * 15 + 127) / 255));
+            assert_eq!(*packed, rounded);
+        }
+        assert_eq!(
+            resize_rgba4444(&rgba4444, 64, 47, 21, 13, "lanczos3", false),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferSize))
+        );
+    }
+
+    #[test]
+    fn test_resize_i420() {
+        // Horizontal ramps, with chroma samples taken at the even luma samples.
+        let y: Vec<u8> = (0..64 * 8).map(|i| (i % 64 * 3 + 20) as u8).collect();
+        let u: Vec<u8> = (0..32 * 4).map(|i| (i % 32 * 6 + 20) as u8).collect();
+        let v: Vec<u8> = u.iter().map(|&c| 255 - c).collect();
+        let resized = resize_i420(&y, 64, &u, 32, &v, 32, 64, 8, 16, 2, "bilinear").unwrap();
+        assert_eq!(resized.len(), 16 * 2 + 8 * 2);
+        assert_eq!(resized[..32], resize_gray(&y, 64, 8, 16, 2, "bilinear").unwrap());
+        // Chroma samples are still at the even luma samples.
+        for x in 1..7 {
+            let luma = resized[x * 2] as i32;
+            assert!((resized[32 + x] as i32 - luma).abs() <= 1, "{x} {resized:?}");
+            assert!((resized[40 + x] as i32 - (255 - luma)).abs() <= 1, "{x} {resized:?}");
+        }
+
+        // Rows with padding give the same result.
+        let pad = |plane: &[u8], width: usize, stride: usize| -> Vec<u8> {
+            plane
+                .chunks_exact(width)
+                .flat_map(|row| row.iter().copied().chain(std::iter::repeat_n(0, stride - width)))
+                .collect()
+        };
+        let padded = resize_i420(
+            &pad(&y, 64, 80), 80, &pad(&u, 32, 48), 48, &pad(&v, 32, 40), 40, 64, 8, 16, 2, "bilinear",
+        )
+        .unwrap();
+        assert_eq!(padded, resized);
+
+        // Odd dimensions round the chroma planes up.
+        let resized = resize_i420(&y[..63 * 7], 63, &u, 32, &v, 32, 63, 7, 5, 3, "lanczos3").unwrap();
+        assert_eq!(resized.len(), 5 * 3 + 3 * 2 * 2);
+
+        assert_eq!(
+            resize_i420(&y, 64, &u[..32 * 3], 32, &v, 32, 64, 8, 16, 2, "bilinear"),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferSize))
+        );
+    }
+
+    #[test]
+    fn test_resize_nv12() {
+        let y = gradient(63 * 7);
+        let u: Vec<u8> = (0..32 * 4).map(|i| (i * 13 % 256) as u8).collect();
+        // Zeros in the second component must not be taken for transparent pixels.
+        let v: Vec<u8> = (0..32 * 4).map(|i| if i % 3 == 0 { 0 } else { (i * 5 % 256) as u8 }).collect();
+        let uv: Vec<u8> = u.iter().zip(&v).flat_map(|(&u, &v)| [u, v]).collect();
+        for algorithm in ["nearest", "bilinear", "lanczos3"] {
+            let expected = resize_i420(&y, 63, &u, 32, &v, 32, 63, 7, 21, 5, algorithm).unwrap();
+            let (expected_y, expected_chroma) = expected.split_at(21 * 5);
+            let (expected_u, expected_v) = expected_chroma.split_at(11 * 3);
+            let resized = resize_nv12(&y, 63, &uv, 64, 63, 7, 21, 5, algorithm).unwrap();
+            let (resized_y, resized_uv) = resized.split_at(21 * 5);
+            assert_eq!(resized_y, expected_y);
+            let resized_u: Vec<u8> = resized_uv.iter().step_by(2).copied().collect();
+            let resized_v: Vec<u8> = resized_uv.iter().skip(1).step_by(2).copied().collect();
+            assert_eq!(resized_u, expected_u);
+            assert_eq!(resized_v, expected_v);
+        }
+        assert_eq!(
+            resize_nv12(&y, 63, &uv, 63, 63, 7, 21, 5, "bilinear"),
+            Err(ResizeError::ImageBufferError(ImageBufferError::InvalidBufferAlignment))
+        );
+    }
+
+    #[test]
+    fn test_resize_region() {
+        let data = noise(67 * 45 * 4);
+        for algorithm in ["nearest", "bilinear", "lanczos3", "supersampling:catmullrom:2"] {
+            for linear_light in [false, true] {
+                // Both passes, horizontal pass only, vertical pass only and no pass at all.
+                for (w, h) in [(31, 17), (20, 45), (67, 91), (67, 45)] {
+                    let full = resize(&data, 67, 45, w, h, algorithm, linear_light).unwrap();
+                    for region in [(0, 0, w, h), (3, 5, w - 7, h - 9), (0, 4, w, 6), (w - 1, h - 1, 1, 1)] {
+                        let (left, top, width, height) = region;
+                        let resized =
+                            resize_region(&data, 67, 45, w, h, left, top, width, height, algorithm, linear_light).unwrap();
+                        assert_eq!(resized, region_of(&full, w, 4, region), "{algorithm} {w}x{h} {region:?}");
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            resize_region(&data, 67, 45, 31, 17, 3, 5, 29, 12, "bilinear", false),
+            Err(ResizeError::RegionOutOfBounds)
+        );
+        assert_eq!(
+            resize_region(&data, 67, 45, 31, 17, usize::MAX, 5, 2, 2, "bilinear", false),
+            Err(ResizeError::RegionOutOfBounds)
+        );
+        assert_eq!(resize_region(&data, 67, 45, 31, 17, 3, 5, 0, 2, "bilinear", false), Err(ResizeError::ZeroDimensions));
     }
 }
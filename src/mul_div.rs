@@ -0,0 +1,290 @@
+use crate::alpha::{layout, AlphaMulDiv};
+use crate::pixels::{PixelExt, PixelLayout};
+use crate::{
+    CpuExtensions, DifferentDimensionsError, DynamicImageView, DynamicImageViewMut, ImageView,
+    ImageViewMut, ResizeError,
+};
+
+/// Methods of this structure used to multiply or divide RGB-channels
+/// by alpha-channel.
+///
+/// By default, instance of `MulDiv` created with best CPU-extensions provided by your CPU.
+/// `U8x4` pixels are treated as `Rgba`, use [MulDiv::set_pixel_layout] to change this.
+#[derive(Default, Debug, Clone)]
+pub struct MulDiv {
+    cpu_extensions: CpuExtensions,
+    pixel_layout: PixelLayout,
+}
+
+impl MulDiv {
+    pub fn cpu_extensions(&self) -> CpuExtensions {
+        self.cpu_extensions
+    }
+
+    /// Sets CPU extensions used by the next calls.
+    /// Returns an error if your CPU doesn't support the extensions.
+    pub fn set_cpu_extensions(&mut self, extensions: CpuExtensions) -> Result<(), ResizeError> {
+        if !extensions.is_supported() {
+            return Err(ResizeError::UnsupportedCpuExtensions);
+        }
+        self.cpu_extensions = extensions;
+        Ok(())
+    }
+
+    pub fn pixel_layout(&self) -> PixelLayout {
+        self.pixel_layout
+    }
+
+    /// Sets the layout of `U8x4` images, which tells where their alpha channel is.
+    pub fn set_pixel_layout(&mut self, pixel_layout: PixelLayout) {
+        self.pixel_layout = pixel_layout;
+    }
+
+    /// Returns `true` if alpha channel of `U8x4` images isn't the last component,
+    /// so that the SIMD kernels can't be used.
+    fn alpha_is_not_last(&self) -> bool {
+        self.pixel_layout.alpha_index() != 3
+    }
+
+    /// Multiplies RGB-channels of source image by alpha-channel and store
+    /// result into destination image.
+    pub fn multiply_alpha(
+        &self,
+        src_image: &DynamicImageView,
+        dst_image: &mut DynamicImageViewMut,
+    ) -> Result<(), ResizeError> {
+        match (src_image, dst_image) {
+            (DynamicImageView::U8(src), DynamicImageViewMut::U8(dst)) => {
+                check_dimensions(src, dst)?;
+                dst.copy_from_view(src);
+            }
+            (DynamicImageView::U8x3(src), DynamicImageViewMut::U8x3(dst)) => {
+                check_dimensions(src, dst)?;
+                dst.copy_from_view(src);
+            }
+            (DynamicImageView::F32(src), DynamicImageViewMut::F32(dst)) => {
+                check_dimensions(src, dst)?;
+                dst.copy_from_view(src);
+            }
+            (DynamicImageView::Rgb565(src), DynamicImageViewMut::Rgb565(dst)) => {
+                check_dimensions(src, dst)?;
+                dst.copy_from_view(src);
+            }
+            (DynamicImageView::U8x2(src), DynamicImageViewMut::U8x2(dst)) => {
+                check_dimensions(src, dst)?;
+                AlphaMulDiv::multiply_alpha(src, dst, self.cpu_extensions);
+            }
+            (DynamicImageView::U8x4(src), DynamicImageViewMut::U8x4(dst)) => {
+                check_dimensions(src, dst)?;
+                if self.alpha_is_not_last() {
+                    layout::multiply_alpha(src, dst, self.pixel_layout);
+                } else {
+                    AlphaMulDiv::multiply_alpha(src, dst, self.cpu_extensions);
+                }
+            }
+            (DynamicImageView::U16x4(src), DynamicImageViewMut::U16x4(dst)) => {
+                check_dimensions(src, dst)?;
+                AlphaMulDiv::multiply_alpha(src, dst, self.cpu_extensions);
+            }
+            (DynamicImageView::F32x4(src), DynamicImageViewMut::F32x4(dst)) => {
+                check_dimensions(src, dst)?;
+                AlphaMulDiv::multiply_alpha(src, dst, self.cpu_extensions);
+            }
+            (DynamicImageView::Rgba4444(src), DynamicImageViewMut::Rgba4444(dst)) => {
+                check_dimensions(src, dst)?;
+                AlphaMulDiv::multiply_alpha(src, dst, self.cpu_extensions);
+            }
+            _ => return Err(ResizeError::DifferentTypesOfPixels),
+        }
+        Ok(())
+    }
+
+    /// Multiplies RGB-channels of image by alpha-channel inplace.
+    pub fn multiply_alpha_inplace(&self, image: &mut DynamicImageViewMut) -> Result<(), ResizeError> {
+        match image {
+            // Images without alpha channel are left as is.
+            DynamicImageViewMut::U8(_)
+            | DynamicImageViewMut::U8x3(_)
+            | DynamicImageViewMut::F32(_)
+            | DynamicImageViewMut::Rgb565(_) => (),
+            DynamicImageViewMut::U8x2(image) => {
+                AlphaMulDiv::multiply_alpha_inplace(image, self.cpu_extensions);
+            }
+            DynamicImageViewMut::U8x4(image) => {
+                if self.alpha_is_not_last() {
+                    layout::multiply_alpha_inplace(image, self.pixel_layout);
+                } else {
+                    AlphaMulDiv::multiply_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            DynamicImageViewMut::U16x4(image) => {
+                AlphaMulDiv::multiply_alpha_inplace(image, self.cpu_extensions);
+            }
+            DynamicImageViewMut::F32x4(image) => {
+                AlphaMulDiv::multiply_alpha_inplace(image, self.cpu_extensions);
+            }
+            DynamicImageViewMut::Rgba4444(image) => {
+                AlphaMulDiv::multiply_alpha_inplace(image, self.cpu_extensions);
+            }
+        }
+        Ok(())
+    }
+
+    /// Divides RGB-channels of source image by alpha-channel and store
+    /// result into destination image.
+    pub fn divide_alpha(
+        &self,
+        src_image: &DynamicImageView,
+        dst_image: &mut DynamicImageViewMut,
+    ) -> Result<(), ResizeError> {
+        match (src_image, dst_image) {
+            (DynamicImageView::U8(src), DynamicImageViewMut::U8(dst)) => {
+                check_dimensions(src, dst)?;
+                dst.copy_from_view(src);
+            }
+            (DynamicImageView::U8x3(src), DynamicImageViewMut::U8x3(dst)) => {
+                check_dimensions(src, dst)?;
+                dst.copy_from_view(src);
+            }
+            (DynamicImageView::F32(src), DynamicImageViewMut::F32(dst)) => {
+                check_dimensions(src, dst)?;
+                dst.copy_from_view(src);
+            }
+            (DynamicImageView::Rgb565(src), DynamicImageViewMut::Rgb565(dst)) => {
+                check_dimensions(src, dst)?;
+                dst.copy_from_view(src);
+            }
+            (DynamicImageView::U8x2(src), DynamicImageViewMut::U8x2(dst)) => {
+                check_dimensions(src, dst)?;
+                AlphaMulDiv::divide_alpha(src, dst, self.cpu_extensions);
+            }
+            (DynamicImageView::U8x4(src), DynamicImageViewMut::U8x4(dst)) => {
+                check_dimensions(src, dst)?;
+                if self.alpha_is_not_last() {
+                    layout::divide_alpha(src, dst, self.pixel_layout, self.pixel_layout);
+                } else {
+                    AlphaMulDiv::divide_alpha(src, dst, self.cpu_extensions);
+                }
+            }
+            (DynamicImageView::U16x4(src), DynamicImageViewMut::U16x4(dst)) => {
+                check_dimensions(src, dst)?;
+                AlphaMulDiv::divide_alpha(src, dst, self.cpu_extensions);
+            }
+            (DynamicImageView::F32x4(src), DynamicImageViewMut::F32x4(dst)) => {
+                check_dimensions(src, dst)?;
+                AlphaMulDiv::divide_alpha(src, dst, self.cpu_extensions);
+            }
+            (DynamicImageView::Rgba4444(src), DynamicImageViewMut::Rgba4444(dst)) => {
+                check_dimensions(src, dst)?;
+                AlphaMulDiv::divide_alpha(src, dst, self.cpu_extensions);
+            }
+            _ => return Err(ResizeError::DifferentTypesOfPixels),
+        }
+        Ok(())
+    }
+
+    /// Divides RGB-channels of image by alpha-channel inplace.
+    pub fn divide_alpha_inplace(&self, image: &mut DynamicImageViewMut) -> Result<(), ResizeError> {
+        match image {
+            // Images without alpha channel are left as is.
+            DynamicImageViewMut::U8(_)
+            | DynamicImageViewMut::U8x3(_)
+            | DynamicImageViewMut::F32(_)
+            | DynamicImageViewMut::Rgb565(_) => (),
+            DynamicImageViewMut::U8x2(image) => {
+                AlphaMulDiv::divide_alpha_inplace(image, self.cpu_extensions);
+            }
+            DynamicImageViewMut::U8x4(image) => {
+                if self.alpha_is_not_last() {
+                    layout::divide_alpha_inplace(image, self.pixel_layout, self.pixel_layout);
+                } else {
+                    AlphaMulDiv::divide_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            DynamicImageViewMut::U16x4(image) => {
+                AlphaMulDiv::divide_alpha_inplace(image, self.cpu_extensions);
+            }
+            DynamicImageViewMut::F32x4(image) => {
+                AlphaMulDiv::divide_alpha_inplace(image, self.cpu_extensions);
+            }
+            DynamicImageViewMut::Rgba4444(image) => {
+                AlphaMulDiv::divide_alpha_inplace(image, self.cpu_extensions);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_dimensions<P: PixelExt>(
+    src_image: &ImageView<P>,
+    dst_image: &ImageViewMut<P>,
+) -> Result<(), DifferentDimensionsError> {
+    if src_image.width() != dst_image.width() || src_image.height() != dst_image.height() {
+        return Err(DifferentDimensionsError);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+    use crate::test_utils::noise;
+    use crate::{CpuExtensions, ImageView, ImageViewMut, MulDiv, ResizeError, U8x2, U8x4};
+
+    #[test]
+    fn test_different_dimensions() {
+        let src = [255, 128, 0, 128];
+        let mut dst = [0; 8];
+        let src_view = ImageView::<U8x4>::from_buffer(1, 1, &src).unwrap();
+        let dst_view = ImageViewMut::<U8x4>::from_buffer(2, 1, &mut dst).unwrap();
+        assert_eq!(
+            MulDiv::default().multiply_alpha(&src_view.into(), &mut dst_view.into()),
+            Err(ResizeError::DifferentDimensions)
+        );
+    }
+
+    #[test]
+    fn test_gray_alpha() {
+        let mut data = [200, 128, 10, 0];
+        let mut image = ImageViewMut::<U8x2>::from_buffer(2, 1, &mut data).unwrap().into();
+        MulDiv::default().multiply_alpha_inplace(&mut image).unwrap();
+        assert_eq!(data, [100, 128, 0, 0]);
+    }
+
+    #[test]
+    fn test_cpu_extensions() {
+        // SIMD kernels must produce the same results as the scalar ones.
+        let mut extensions = vec![
+            CpuExtensions::None,
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Sse4_1,
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Avx2,
+            #[cfg(target_feature = "simd128")]
+            CpuExtensions::Simd128,
+        ];
+        extensions.retain(CpuExtensions::is_supported);
+        let data = noise(67 * 45 * 4);
+        let src_view = ImageView::<U8x4>::from_buffer(67, 45, &data).unwrap().into();
+        let mut results = extensions.iter().map(|&e| {
+            let mut mul_div = MulDiv::default();
+            mul_div.set_cpu_extensions(e).unwrap();
+            let mut multiplied = vec![0; data.len()];
+            let mut divided = vec![0; data.len()];
+            let dst_view = ImageViewMut::<U8x4>::from_buffer(67, 45, &mut multiplied).unwrap();
+            mul_div.multiply_alpha(&src_view, &mut dst_view.into()).unwrap();
+            let dst_view = ImageViewMut::<U8x4>::from_buffer(67, 45, &mut divided).unwrap();
+            mul_div.divide_alpha(&src_view, &mut dst_view.into()).unwrap();
+            let image = ImageViewMut::<U8x4>::from_buffer(67, 45, &mut multiplied).unwrap();
+            mul_div.divide_alpha_inplace(&mut image.into()).unwrap();
+            let image = ImageViewMut::<U8x4>::from_buffer(67, 45, &mut divided).unwrap();
+            mul_div.multiply_alpha_inplace(&mut image.into()).unwrap();
+            (multiplied, divided)
+        });
+        let expected = results.next().unwrap();
+        for result in results {
+            assert!(result == expected);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::pixels::{F32, F32x4, PixelExt, Rgb565, Rgba4444, U16x4, U8, U8x2, U8x3, U8x4};
+use crate::resizer::OutputRegion;
+use crate::{CpuExtensions, CropBox, ImageView, ImageViewMut};
+
+#[cfg(target_feature = "simd128")]
+mod wasm32;
+
+pub(crate) trait Nearest
+where
+    Self: PixelExt,
+{
+    /// Resizes crop box of source image into the region of a bigger image
+    /// given by destination image, by taking the nearest source pixel
+    /// for every destination pixel.
+    fn resample_nearest(
+        src_image: &ImageView<Self>,
+        dst_image: &mut ImageViewMut<Self>,
+        crop_box: CropBox,
+        region: OutputRegion,
+        _cpu_extensions: CpuExtensions,
+    ) {
+        resample_nearest(src_image, dst_image, crop_box, region);
+    }
+}
+
+impl Nearest for U8x4 {
+    fn resample_nearest(
+        src_image: &ImageView<Self>,
+        dst_image: &mut ImageViewMut<Self>,
+        crop_box: CropBox,
+        region: OutputRegion,
+        cpu_extensions: CpuExtensions,
+    ) {
+        match cpu_extensions {
+            #[cfg(target_feature = "simd128")]
+            CpuExtensions::Simd128 if region.left == 0 && region.full_width == dst_image.width() => {
+                if let Some(factor) = integer_upscale_factor(dst_image.width(), crop_box) {
+                    let left = crop_box.left as usize;
+                    let width = crop_box.width as usize;
+                    resample_rows(src_image, dst_image, crop_box, region, |src_row, dst_row| unsafe {
+                        wasm32::upscale_row(&src_row[left..left + width], dst_row, factor)
+                    });
+                    return;
+                }
+            }
+            _ => (),
+        }
+        resample_nearest(src_image, dst_image, crop_box, region);
+    }
+}
+
+impl Nearest for U8 {}
+
+impl Nearest for U8x2 {}
+
+impl Nearest for U8x3 {}
+
+impl Nearest for U16x4 {}
+
+impl Nearest for F32 {}
+
+impl Nearest for F32x4 {}
+
+impl Nearest for Rgb565 {}
+
+impl Nearest for Rgba4444 {}
+
+fn resample_nearest<P: PixelExt>(
+    src_image: &ImageView<P>,
+    dst_image: &mut ImageViewMut<P>,
+    crop_box: CropBox,
+    region: OutputRegion,
+) {
+    let x_in = x_indexes(src_image.width(), dst_image.width(), crop_box, region);
+    resample_rows(src_image, dst_image, crop_box, region, |src_row, dst_row| {
+        for (&x, dst_pixel) in x_in.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = unsafe { *src_row.get_unchecked(x) };
+        }
+    });
+}
+
+/// Returns index of source pixel for every pixel of destination row.
+fn x_indexes(src_width: usize, dst_width: usize, crop_box: CropBox, region: OutputRegion) -> Vec<usize> {
+    let scale = crop_box.width / region.full_width as f64;
+    (region.left..region.left + dst_width)
+        .map(|x| ((crop_box.left + (x as f64 + 0.5) * scale) as usize).min(src_width - 1))
+        .collect()
+}
+
+/// Returns the factor of the horizontal upscale if every source pixel
+/// of the crop box is repeated the same number of times.
+#[cfg(target_feature = "simd128")]
+fn integer_upscale_factor(dst_width: usize, crop_box: CropBox) -> Option<usize> {
+    if crop_box.left.fract() != 0.0 || crop_box.width.fract() != 0.0 {
+        return None;
+    }
+    let width = crop_box.width as usize;
+    (dst_width.is_multiple_of(width) && dst_width > width).then_some(dst_width / width)
+}
+
+/// Fills every row of destination image from the nearest row of source image.
+/// Rows that use the same source row as the previous one are copied from it.
+fn resample_rows<P: PixelExt>(
+    src_image: &ImageView<P>,
+    dst_image: &mut ImageViewMut<P>,
+    crop_box: CropBox,
+    region: OutputRegion,
+    mut resample_row: impl FnMut(&[P], &mut [P]),
+) {
+    let src_height = src_image.height();
+    let scale = crop_box.height / region.full_height as f64;
+    let mut prev_row: Option<(usize, &[P])> = None;
+    for (y, dst_row) in (region.top..).zip(dst_image.iter_rows_mut()) {
+        let y_in = ((crop_box.top + (y as f64 + 0.5) * scale) as usize).min(src_height - 1);
+        match prev_row {
+            Some((prev_y_in, prev_dst_row)) if prev_y_in == y_in => {
+                dst_row.copy_from_slice(prev_dst_row);
+            }
+            _ => {
+                if let Some(src_row) = src_image.get_row(y_in) {
+                    resample_row(src_row, dst_row);
+                }
+            }
+        }
+        prev_row = Some((y_in, dst_row));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+    use crate::{resize, ResizeAlg, Resizer};
+
+    #[test]
+    fn test_nearest() {
+        let data: Vec<u8> = (0..4 * 2 * 4).map(|i| (i * 9 % 256) as u8).collect();
+        let pixel = |x: usize, y: usize| &data[(y * 4 + x) * 4..(y * 4 + x + 1) * 4];
+
+        // Upscale repeats every pixel, including the transparent ones.
+        let upscaled = resize(&data, 4, 2, 12, 4, "nearest", true).unwrap();
+        for (i, dst_pixel) in upscaled.chunks_exact(4).enumerate() {
+            assert_eq!(dst_pixel, pixel(i % 12 / 3, i / 12 / 2));
+        }
+
+        // Downscale takes the pixels nearest to the centers.
+        let downscaled = resize(&data, 4, 2, 2, 1, "Nearest", false).unwrap();
+        assert_eq!(downscaled, [pixel(1, 1), pixel(3, 1)].concat());
+
+        let mut resizer = Resizer::new(ResizeAlg::Nearest);
+        resizer.set_crop(1.0, 0.0, 2.0, 2.0);
+        let cropped = resizer.resize_rgba(&data, 4, 2, 6, 2).unwrap();
+        assert_eq!(cropped[..12], pixel(1, 0).repeat(3));
+        assert_eq!(cropped[12..24], pixel(2, 0).repeat(3));
+    }
+}
@@ -0,0 +1,22 @@
+use std::arch::wasm32::*;
+
+use crate::pixels::U8x4;
+
+/// Repeats every pixel of source row `factor` times.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(crate) unsafe fn upscale_row(src_row: &[U8x4], dst_row: &mut [U8x4], factor: usize) {
+    unsafe {
+        let dst_chunks = dst_row.chunks_exact_mut(factor);
+        for (&src_pixel, dst_chunk) in src_row.iter().zip(dst_chunks) {
+            let pixels = u32x4_splat(u32::from_ne_bytes(src_pixel.0));
+            let mut dst_pixels = dst_chunk.chunks_exact_mut(4);
+            for dst in &mut dst_pixels {
+                v128_store(dst.as_mut_ptr() as *mut v128, pixels);
+            }
+            for dst_pixel in dst_pixels.into_remainder() {
+                *dst_pixel = src_pixel;
+            }
+        }
+    }
+}
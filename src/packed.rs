@@ -0,0 +1,148 @@
+//! Pixels packed into 16 bits, which are unpacked into pixels with one byte
+//! per component to be resized and packed again afterwards.
+use crate::pixels::{PixelExt, Rgb565, Rgba4444, U8x3, U8x4};
+use crate::{ImageView, ImageViewMut};
+
+/// Threshold used to round a component to the nearest value with less bits.
+pub(crate) const ROUNDING_THRESHOLD: u8 = 7;
+
+/// Thresholds of ordered dithering, in 1/16 of the step between two values
+/// with less bits.
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+static TABLE_4: [[u8; 2]; 256] = quantization_table(4);
+static TABLE_5: [[u8; 2]; 256] = quantization_table(5);
+static TABLE_6: [[u8; 2]; 256] = quantization_table(6);
+
+pub(crate) trait PackedPixel: PixelExt {
+    /// Type of pixels with one byte per component.
+    type Unpacked: PixelExt;
+
+    fn unpack(self) -> Self::Unpacked;
+
+    /// Packs components of pixel, rounding them up if the remainder of their
+    /// division by the step between two packed values (in 1/16 of the step)
+    /// is greater than `threshold`.
+    fn pack(pixel: Self::Unpacked, threshold: u8) -> Self;
+}
+
+impl PackedPixel for Rgb565 {
+    type Unpacked = U8x3;
+
+    #[inline(always)]
+    fn unpack(self) -> U8x3 {
+        let v = self.0;
+        U8x3::new([
+            expand(v >> 11, 5) as u8,
+            expand((v >> 5) & 0x3f, 6) as u8,
+            expand(v & 0x1f, 5) as u8,
+        ])
+    }
+
+    #[inline(always)]
+    fn pack(pixel: U8x3, threshold: u8) -> Self {
+        let [r, g, b] = pixel.0;
+        Self::new(
+            (quantize(&TABLE_5, r, threshold) << 11)
+                | (quantize(&TABLE_6, g, threshold) << 5)
+                | quantize(&TABLE_5, b, threshold),
+        )
+    }
+}
+
+impl PackedPixel for Rgba4444 {
+    type Unpacked = U8x4;
+
+    #[inline(always)]
+    fn unpack(self) -> U8x4 {
+        let v = self.0;
+        U8x4::new([
+            (v >> 12) as u8 * 17,
+            ((v >> 8) & 0xf) as u8 * 17,
+            ((v >> 4) & 0xf) as u8 * 17,
+            (v & 0xf) as u8 * 17,
+        ])
+    }
+
+    /// Alpha channel is always rounded, because dithering it would make
+    /// the edges of opaque areas noisy.
+    #[inline(always)]
+    fn pack(pixel: U8x4, threshold: u8) -> Self {
+        let [r, g, b, a] = pixel.0;
+        Self::new(
+            (quantize(&TABLE_4, r, threshold) << 12)
+                | (quantize(&TABLE_4, g, threshold) << 8)
+                | (quantize(&TABLE_4, b, threshold) << 4)
+                | quantize(&TABLE_4, a, ROUNDING_THRESHOLD),
+        )
+    }
+}
+
+pub(crate) fn unpack<P: PackedPixel>(src_image: &ImageView<P>, dst_image: &mut ImageViewMut<P::Unpacked>) {
+    for (src_row, dst_row) in src_image.iter_rows(0).zip(dst_image.iter_rows_mut()) {
+        for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row.iter_mut()) {
+            *dst_pixel = src_pixel.unpack();
+        }
+    }
+}
+
+/// Packs source image into destination image, with ordered dithering
+/// of the color components if `dithering` is set.
+/// The dithering pattern starts at `(left, top)` position, so that it is
+/// continuous when an image is packed by parts.
+pub(crate) fn pack<P: PackedPixel>(
+    src_image: &ImageView<P::Unpacked>,
+    dst_image: &mut ImageViewMut<P>,
+    dithering: bool,
+    (left, top): (usize, usize),
+) {
+    let rows = src_image.iter_rows(0).zip(dst_image.iter_rows_mut());
+    for (y, (src_row, dst_row)) in (top..).zip(rows) {
+        let thresholds = if dithering {
+            BAYER_4X4[y % 4]
+        } else {
+            [ROUNDING_THRESHOLD; 4]
+        };
+        let pixels = src_row.iter().zip(dst_row.iter_mut());
+        for (x, (src_pixel, dst_pixel)) in (left..).zip(pixels) {
+            *dst_pixel = P::pack(*src_pixel, thresholds[x % 4]);
+        }
+    }
+}
+
+/// Converts a value with given count of bits into a byte by replicating its bits.
+#[inline(always)]
+const fn expand(v: u16, bits: u32) -> u16 {
+    (v << (8 - bits)) | (v >> (2 * bits - 8))
+}
+
+#[inline(always)]
+fn quantize(table: &[[u8; 2]; 256], v: u8, threshold: u8) -> u16 {
+    let [lower, remainder] = table[v as usize];
+    lower as u16 + (remainder > threshold) as u16
+}
+
+/// Creates a table with, for every byte, the greatest value with given count
+/// of bits that is not greater once expanded, and the remainder of the byte
+/// in 1/16 of the step to the next value. Bytes that are expanded values are
+/// thus always packed into these values, with or without dithering.
+const fn quantization_table(bits: u32) -> [[u8; 2]; 256] {
+    let mut res = [[0; 2]; 256];
+    let max = (1 << bits) - 1;
+    let mut lower: u16 = 0;
+    let mut v: u16 = 0;
+    while v < 256 {
+        while lower < max && expand(lower + 1, bits) <= v {
+            lower += 1;
+        }
+        let remainder = if lower == max {
+            0
+        } else {
+            let lower_value = expand(lower, bits);
+            (v - lower_value) * 16 / (expand(lower + 1, bits) - lower_value)
+        };
+        res[v as usize] = [lower as u8, remainder as u8];
+        v += 1;
+    }
+    res
+}
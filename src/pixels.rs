@@ -3,20 +3,117 @@ use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
 use std::mem::size_of;
 use std::slice;
+use std::str::FromStr;
+
+use crate::{ResizeError, UnknownPixelLayoutError};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum PixelType {
-    U8x4
+    U8,
+    U8x2,
+    U8x3,
+    U8x4,
+    U16x4,
+    F32,
+    F32x4,
+    Rgb565,
+    Rgba4444,
 }
 
 impl PixelType {
-    pub(crate) fn size(&self) -> usize { 4 }
+    pub(crate) fn size(&self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::U8x2 => 2,
+            Self::U8x3 => 3,
+            Self::U8x4 => 4,
+            Self::U16x4 => 8,
+            Self::F32 => 4,
+            Self::F32x4 => 16,
+            Self::Rgb565 | Self::Rgba4444 => 2,
+        }
+    }
+
+    /// Returns size in bytes of the buffer required to store an image
+    /// with given dimensions.
+    pub(crate) fn buffer_size(&self, width: usize, height: usize) -> Result<usize, ResizeError> {
+        if width == 0 || height == 0 {
+            return Err(ResizeError::ZeroDimensions);
+        }
+        width
+            .checked_mul(height)
+            .and_then(|count| count.checked_mul(self.size()))
+            .ok_or(ResizeError::SizeOverflow)
+    }
 
     /// Returns `true` if given buffer is aligned by the alignment of pixel.
     pub(crate) fn is_aligned(&self, buffer: &[u8]) -> bool {
         match self {
+            Self::U8 | Self::U8x3 => true,
+            Self::U8x2 => unsafe { buffer.align_to::<U8x2>() }.0.is_empty(),
             Self::U8x4 => unsafe { buffer.align_to::<U8x4>() }.0.is_empty(),
+            Self::U16x4 => unsafe { buffer.align_to::<U16x4>() }.0.is_empty(),
+            Self::F32 => unsafe { buffer.align_to::<F32>() }.0.is_empty(),
+            Self::F32x4 => unsafe { buffer.align_to::<F32x4>() }.0.is_empty(),
+            Self::Rgb565 => unsafe { buffer.align_to::<Rgb565>() }.0.is_empty(),
+            Self::Rgba4444 => unsafe { buffer.align_to::<Rgba4444>() }.0.is_empty(),
+        }
+    }
+}
+
+/// Order of the components of pixels with four components,
+/// which tells where alpha channel is.
+///
+/// `Resizer` and `MulDiv` treat `U8x4` pixels as `Rgba` by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PixelLayout {
+    #[default]
+    Rgba,
+    Bgra,
+    Argb,
+    Abgr,
+}
+
+impl PixelLayout {
+    /// Returns the indexes of red, green, blue and alpha components.
+    pub(crate) const fn indexes(&self) -> [usize; 4] {
+        match self {
+            Self::Rgba => [0, 1, 2, 3],
+            Self::Bgra => [2, 1, 0, 3],
+            Self::Argb => [1, 2, 3, 0],
+            Self::Abgr => [3, 2, 1, 0],
+        }
+    }
+
+    /// Returns the index of alpha component.
+    pub const fn alpha_index(&self) -> usize {
+        self.indexes()[3]
+    }
+
+    /// Returns, for every component of a pixel with `to` layout,
+    /// the index of the same component of a pixel with this layout.
+    pub(crate) fn swizzle(&self, to: PixelLayout) -> [usize; 4] {
+        let from = self.indexes();
+        let mut res = [0; 4];
+        for (&from_index, to_index) in from.iter().zip(to.indexes()) {
+            res[to_index] = from_index;
+        }
+        res
+    }
+}
+
+impl FromStr for PixelLayout {
+    type Err = UnknownPixelLayoutError;
+
+    /// Parses the name of a layout: `rgba`, `bgra`, `argb` or `abgr`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "rgba" => Ok(Self::Rgba),
+            "bgra" => Ok(Self::Bgra),
+            "argb" => Ok(Self::Argb),
+            "abgr" => Ok(Self::Abgr),
+            _ => Err(UnknownPixelLayoutError),
         }
     }
 }
@@ -136,7 +233,6 @@ where
     T: Sized + Copy + Clone + PartialEq + 'static,
     C: PixelComponent,
 {
-    #[cfg(target_arch = "wasm32")]
     #[inline(always)]
     pub const fn new(v: T) -> Self {
         Self(v, PhantomData)
@@ -175,6 +271,23 @@ macro_rules! pixel_struct {
     };
 }
 
+pixel_struct!(U8, u8, u8, 1, PixelType::U8, "One byte per pixel (e.g. L8)");
+pixel_struct!(
+    U8x2,
+    [u8; 2],
+    u8,
+    2,
+    PixelType::U8x2,
+    "Two bytes per pixel (e.g. LA8)"
+);
+pixel_struct!(
+    U8x3,
+    [u8; 3],
+    u8,
+    3,
+    PixelType::U8x3,
+    "Three bytes per pixel (e.g. RGB8)"
+);
 pixel_struct!(
     U8x4,
     [u8; 4],
@@ -183,16 +296,65 @@ pixel_struct!(
     PixelType::U8x4,
     "Four bytes per pixel (RGBA8, RGBx8, CMYK8 and other)"
 );
+pixel_struct!(
+    U16x4,
+    [u16; 4],
+    u16,
+    4,
+    PixelType::U16x4,
+    "Four u16 components per pixel (RGBA16, RGBx16, CMYK16 and other)"
+);
+pixel_struct!(F32, f32, f32, 1, PixelType::F32, "One f32 component per pixel (e.g. L32F)");
+pixel_struct!(
+    F32x4,
+    [f32; 4],
+    f32,
+    4,
+    PixelType::F32x4,
+    "Four f32 components per pixel (e.g. RGBA32F)"
+);
+macro_rules! packed_pixel_struct {
+    ($name:ident, $pixel_type:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, PartialEq)]
+        #[repr(transparent)]
+        pub struct $name(pub u16);
 
-pub trait IntoPixelComponent<Out: PixelComponent>
-where
-    Self: PixelComponent,
-{
-    fn into_component(self) -> Out;
-}
+        impl $name {
+            #[inline(always)]
+            pub const fn new(v: u16) -> Self {
+                Self(v)
+            }
+        }
 
-impl<C: PixelComponent> IntoPixelComponent<C> for C {
-    fn into_component(self) -> C {
-        self
-    }
+        impl IntoPixelType for $name {
+            fn pixel_type() -> PixelType {
+                $pixel_type
+            }
+        }
+
+        impl PixelExt for $name {
+            type Component = u16;
+            type CountOfComponents = Count<1>;
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}({:#06x})", stringify!($name), self.0)
+            }
+        }
+    };
 }
+
+packed_pixel_struct!(
+    Rgb565,
+    PixelType::Rgb565,
+    "RGB packed into one u16: 5 bits of red, 6 bits of green and 5 bits of blue, \
+    from the most significant bits"
+);
+packed_pixel_struct!(
+    Rgba4444,
+    PixelType::Rgba4444,
+    "RGBA packed into one u16 with 4 bits per component, from the most significant bits"
+);
+
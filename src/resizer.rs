@@ -1,32 +1,95 @@
-use crate::convolution::{self, Convolution, FilterType};
+use std::borrow::Cow;
+use std::ops::Range;
+use std::str::FromStr;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::alpha::{layout, AlphaMulDiv};
+use crate::color::mappers::srgb_mapper;
+use crate::convolution::{self, Bound, Coefficients, Convolution, FilterType};
 use crate::image::InnerImage;
-use crate::pixels::PixelExt;
+use crate::nearest::Nearest;
+use crate::packed::{self, PackedPixel};
+use crate::pixels::{F32, F32x4, PixelExt, PixelLayout, PixelType, Rgb565, Rgba4444, U16x4, U8, U8x2, U8x3, U8x4};
 use crate::{
-    DifferentTypesOfPixelsError, DynamicImageView, DynamicImageViewMut, ImageView, ImageViewMut,
+    DynamicImageView, DynamicImageViewMut, ImageBufferError,
+    ImageView, ImageViewMut, ResizeError,
 };
 
 /// SIMD extension of CPU.
 /// Specific variants vary from target architecture.
 /// Look at source code to see all available variants.
+///
+/// On wasm32, `Simd128` is only available if the module is compiled with
+/// `simd128` target feature, and `None` is the portable scalar code path
+/// for the engines without SIMD support.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CpuExtensions {
-    #[cfg(not(target_arch = "wasm32"))]
+    /// Portable scalar code, available on every architecture.
     None,
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(target_arch = "x86_64")]
+    /// SSE4.1 extension of x86_64 architecture, used by the horizontal pass
+    /// of `U8x4` images, by the vertical pass of images with `u8` components
+    /// and by the multiplication and division by alpha of `U8x4` images.
+    /// Other images use the scalar code.
+    Sse4_1,
+    #[cfg(target_arch = "x86_64")]
+    /// AVX2 extension of x86_64 architecture, used by the horizontal pass
+    /// of `U8x4` images and by the vertical pass of images with `u8` components.
+    /// The multiplication and division by alpha use the SSE4.1 kernels,
+    /// and other images the scalar code.
+    Avx2,
+    #[cfg(target_feature = "simd128")]
     /// SIMD extension of Wasm32 architecture
     Simd128,
 }
 
 impl CpuExtensions {
+    /// Returns `true` if your CPU supports the extension.
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::None => true,
+            #[cfg(target_arch = "x86_64")]
+            Self::Sse4_1 => is_x86_feature_detected!("sse4.1"),
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => is_x86_feature_detected!("avx2"),
+            // A module with SIMD instructions can't be compiled without SIMD support.
+            #[cfg(target_feature = "simd128")]
+            Self::Simd128 => true,
+        }
+    }
 
+    /// Name of the extension: `none`, `sse4.1`, `avx2` or `simd128`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            #[cfg(target_arch = "x86_64")]
+            Self::Sse4_1 => "sse4.1",
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => "avx2",
+            #[cfg(target_feature = "simd128")]
+            Self::Simd128 => "simd128",
+        }
+    }
 }
 
 impl Default for CpuExtensions {
-    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(target_arch = "x86_64")]
+    fn default() -> Self {
+        if is_x86_feature_detected!("avx2") {
+            Self::Avx2
+        } else if is_x86_feature_detected!("sse4.1") {
+            Self::Sse4_1
+        } else {
+            Self::None
+        }
+    }
+    #[cfg(not(any(target_arch = "x86_64", target_feature = "simd128")))]
     fn default() -> Self {
         Self::None
     }
-    #[cfg(target_arch = "wasm32")]
+    #[cfg(target_feature = "simd128")]
     fn default() -> Self {
         Self::Simd128
     }
@@ -35,7 +98,15 @@ impl Default for CpuExtensions {
 #[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
 pub enum ResizeAlg {
+    /// Takes the nearest source pixel for every destination pixel,
+    /// so the values are never blended.
+    Nearest,
     Convolution(FilterType),
+    /// Reduces source image with nearest-neighbour resize to `multiplicity`
+    /// times the size of destination image, then runs the convolution.
+    /// It is much faster than `Convolution` for large downscales
+    /// at the cost of some aliasing.
+    SuperSampling(FilterType, u8),
 }
 
 impl Default for ResizeAlg {
@@ -44,12 +115,167 @@ impl Default for ResizeAlg {
     }
 }
 
+impl FromStr for ResizeAlg {
+    type Err = ResizeError;
+
+    /// Parses `nearest`, the name of a convolution filter (see [FilterType::from_str])
+    /// or `supersampling:<filter>:<multiplicity>`, e.g. `supersampling:lanczos3:2`.
+    ///
+    /// Returns [ResizeError::ZeroMultiplicity] if the multiplicity is zero.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("nearest") {
+            return Ok(Self::Nearest);
+        }
+        let mut parts = s.split(':');
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(name), Some(filter), Some(multiplicity), None)
+                if name.eq_ignore_ascii_case("supersampling") =>
+            {
+                let multiplicity = multiplicity.parse().map_err(|_| ResizeError::UnknownFilterType)?;
+                if multiplicity == 0 {
+                    return Err(ResizeError::ZeroMultiplicity);
+                }
+                Ok(Self::SuperSampling(filter.parse()?, multiplicity))
+            }
+            _ => Ok(Self::Convolution(s.parse()?)),
+        }
+    }
+}
+
+/// Sub-rectangle of the source image, in source pixels.
+///
+/// Fractional values are allowed, so that the crop offsets are respected
+/// with sub-pixel precision by the filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CropBox {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl CropBox {
+    /// Crop box that covers a whole image with given dimensions.
+    fn whole(width: usize, height: usize) -> Self {
+        Self {
+            left: 0.0,
+            top: 0.0,
+            width: width as f64,
+            height: height as f64,
+        }
+    }
+
+    /// Crop box of a chroma plane of a YUV 4:2:0 image, for resizing it
+    /// along with the luma plane cropped with this crop box.
+    ///
+    /// Chroma samples are horizontally co-sited with the even luma samples,
+    /// so the left border is shifted by the change of their distance to the
+    /// center of the chroma pixel, and vertically centered between two luma rows.
+    fn chroma_420(&self, dst_luma_width: usize, dst_luma_height: usize, dst_width: usize, dst_height: usize) -> Self {
+        let scale_x = self.width / dst_luma_width as f64;
+        let scale_y = self.height / dst_luma_height as f64;
+        Self {
+            left: self.left / 2.0 + 0.25 * (1.0 - scale_x),
+            top: self.top / 2.0,
+            width: scale_x * dst_width as f64,
+            height: scale_y * dst_height as f64,
+        }
+    }
+
+    /// Returns an error if the crop box is empty or isn't inside an image
+    /// with given dimensions.
+    fn check(&self, width: usize, height: usize) -> Result<(), ResizeError> {
+        let inside = self.left >= 0.0
+            && self.top >= 0.0
+            && self.width > 0.0
+            && self.height > 0.0
+            && self.left + self.width <= width as f64
+            && self.top + self.height <= height as f64;
+        if inside {
+            Ok(())
+        } else {
+            Err(ResizeError::CropBoxOutOfBounds)
+        }
+    }
+}
+
+/// Position of a destination image inside the bigger image with given
+/// dimensions, that a resize into the latter would produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct OutputRegion {
+    pub left: usize,
+    pub top: usize,
+    pub full_width: usize,
+    pub full_height: usize,
+}
+
+impl OutputRegion {
+    /// Region that covers a whole image with given dimensions.
+    pub fn whole(width: usize, height: usize) -> Self {
+        Self {
+            left: 0,
+            top: 0,
+            full_width: width,
+            full_height: height,
+        }
+    }
+
+    /// Region with given dimensions which starts at `left` x `top` inside the image
+    /// with dimensions `full_width` x `full_height`.
+    /// Returns an error if the region isn't inside the latter.
+    pub fn new(
+        left: usize,
+        top: usize,
+        width: usize,
+        height: usize,
+        full_width: usize,
+        full_height: usize,
+    ) -> Result<Self, ResizeError> {
+        let inside = |start: usize, size: usize, full_size: usize| {
+            start.checked_add(size).is_some_and(|end| end <= full_size)
+        };
+        if !inside(left, width, full_width) || !inside(top, height, full_height) {
+            return Err(ResizeError::RegionOutOfBounds);
+        }
+        Ok(Self {
+            left,
+            top,
+            full_width,
+            full_height,
+        })
+    }
+}
+
 /// Methods of this structure used to resize images.
+///
+/// It is exported to javascript as a class, so that the temporary buffer
+/// and the coefficients can be reused by consecutive calls.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Default, Debug, Clone)]
 pub struct Resizer {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
     pub algorithm: ResizeAlg,
     cpu_extensions: CpuExtensions,
-    convolution_buffer: Vec<u8>
+    crop_box: Option<CropBox>,
+    output_region: Option<OutputRegion>,
+    premultiplied_input: bool,
+    premultiplied_output: bool,
+    linear_light: bool,
+    input_layout: PixelLayout,
+    output_layout: PixelLayout,
+    dithering: bool,
+    convolution_buffer: Vec<u8>,
+    alpha_buffer: Vec<u8>,
+    super_sampling_buffer: Vec<u8>,
+    linear_src_buffer: Vec<u8>,
+    linear_dst_buffer: Vec<u8>,
+    unpacked_src_buffer: Vec<u8>,
+    unpacked_dst_buffer: Vec<u8>,
+    horiz_coeffs: Vec<CachedCoefficients>,
+    vert_coeffs: Vec<CachedCoefficients>,
+    source_buffer: Vec<u8>,
+    source_size: (usize, usize),
+    destination_buffer: Vec<u8>,
 }
 
 impl Resizer {
@@ -64,51 +290,1019 @@ impl Resizer {
         }
     }
 
-    /// Resize source image to the size of destination image and save
-    /// the result to the latter's pixel buffer.
+    /// Creates instance of `Resizer` using the CatmullRom (hq) or the Hamming filter.
+    pub fn with_hq(hq: bool) -> Self {
+        Self::new(ResizeAlg::Convolution(if hq {
+            FilterType::CatmullRom
+        } else {
+            FilterType::Hamming
+        }))
+    }
+
+    /// CPU extensions used by the next calls.
+    pub fn cpu_extensions(&self) -> CpuExtensions {
+        self.cpu_extensions
+    }
+
+    /// Sets CPU extensions used by the next calls, for example to force
+    /// a lower level than the one detected at runtime.
+    /// Returns an error if your CPU doesn't support the extensions.
+    pub fn set_cpu_extensions(&mut self, extensions: CpuExtensions) -> Result<(), ResizeError> {
+        if !extensions.is_supported() {
+            return Err(ResizeError::UnsupportedCpuExtensions);
+        }
+        self.cpu_extensions = extensions;
+        Ok(())
+    }
+
+    /// Crop box of the source image used by the next calls, if any.
+    pub fn crop_box(&self) -> Option<CropBox> {
+        self.crop_box
+    }
+
+    /// Sets the sub-rectangle of the source image that is resized by the next calls.
+    /// `None` resizes the whole source image.
+    pub fn set_crop_box(&mut self, crop_box: Option<CropBox>) {
+        self.crop_box = crop_box;
+    }
+
+    /// Layouts of the source and destination `U8x4` images.
+    pub fn pixel_layouts(&self) -> (PixelLayout, PixelLayout) {
+        (self.input_layout, self.output_layout)
+    }
+
+    /// Sets the layouts of the source and destination `U8x4` images used by the
+    /// next calls, which tell where their alpha channel is.
+    /// The components are reordered if the layouts differ.
+    pub fn set_pixel_layouts(&mut self, input: PixelLayout, output: PixelLayout) {
+        self.input_layout = input;
+        self.output_layout = output;
+    }
+
+    /// Resize source image (or its crop box) to the size of destination image
+    /// and save the result to the latter's pixel buffer.
     ///
-    /// This method doesn't multiply source image and doesn't divide
-    /// destination image by alpha channel.
-    /// You must use [MulDiv](crate::MulDiv) for these actions.
+    /// For pixels with alpha channel, the source image is multiplied by
+    /// alpha channel before the convolution and the destination image is
+    /// divided by alpha channel afterwards, so that transparent pixels
+    /// don't bleed into the visible ones.
+    /// Use [Resizer::set_premultiplied_input] and [Resizer::set_premultiplied_output]
+    /// to skip one of these steps.
+    ///
+    /// With [Resizer::set_linear_light], `U8x4` images are resized in linear light.
+    ///
+    /// With `rayon` feature, bands of destination rows are resized in parallel
+    /// with the same result.
+    ///
+    /// Packed pixels (`Rgb565` and `Rgba4444`) are unpacked into pixels with one
+    /// byte per component before the convolution and packed again afterwards,
+    /// with ordered dithering if [Resizer::set_dithering] is set.
     pub fn resize(
         &mut self,
         src_image: &DynamicImageView,
         dst_image: &mut DynamicImageViewMut,
-    ) -> Result<(), DifferentTypesOfPixelsError> {
+    ) -> Result<(), ResizeError> {
         match (src_image, dst_image) {
             (DynamicImageView::U8x4(src), DynamicImageViewMut::U8x4(dst)) => {
-                self.resize_inner(src, dst);
+                if self.linear_light && self.blends_pixels() {
+                    self.resize_in_linear_light(src, dst)?;
+                } else if self.input_layout == self.output_layout
+                    && self.input_layout.alpha_index() == 3
+                {
+                    self.resize_with_alpha(src, dst)?;
+                } else {
+                    self.resize_with_layouts(src, dst)?;
+                }
+            }
+            (DynamicImageView::U8(src), DynamicImageViewMut::U8(dst)) => {
+                self.resize_inner(src, dst)?;
+            }
+            (DynamicImageView::U8x3(src), DynamicImageViewMut::U8x3(dst)) => {
+                self.resize_inner(src, dst)?;
+            }
+            (DynamicImageView::U8x2(src), DynamicImageViewMut::U8x2(dst)) => {
+                self.resize_with_alpha(src, dst)?;
+            }
+            (DynamicImageView::U16x4(src), DynamicImageViewMut::U16x4(dst)) => {
+                self.resize_with_alpha(src, dst)?;
+            }
+            (DynamicImageView::F32(src), DynamicImageViewMut::F32(dst)) => {
+                self.resize_inner(src, dst)?;
+            }
+            (DynamicImageView::F32x4(src), DynamicImageViewMut::F32x4(dst)) => {
+                self.resize_with_alpha(src, dst)?;
+            }
+            (DynamicImageView::Rgb565(src), DynamicImageViewMut::Rgb565(dst)) => {
+                self.resize_packed(src, dst, Self::resize_inner)?;
+            }
+            (DynamicImageView::Rgba4444(src), DynamicImageViewMut::Rgba4444(dst)) => {
+                self.resize_packed(src, dst, Self::resize_with_alpha)?;
+            }
+            _ => return Err(ResizeError::DifferentTypesOfPixels),
+        }
+        Ok(())
+    }
+
+    /// Computes only the part of the image with dimensions `dst_width` x `dst_height`
+    /// that [Resizer::resize] would produce, which starts at `left` x `top`
+    /// and has the dimensions of destination image, and saves it to the
+    /// latter's pixel buffer.
+    ///
+    /// The result is equal to the matching region of a full resize, and only
+    /// the source rows used by the region are convolved, so that a resize can
+    /// be split into parts (e.g. bands of rows resized by different workers).
+    pub fn resize_region(
+        &mut self,
+        src_image: &DynamicImageView,
+        dst_image: &mut DynamicImageViewMut,
+        dst_width: usize,
+        dst_height: usize,
+        left: usize,
+        top: usize,
+    ) -> Result<(), ResizeError> {
+        self.output_region = Some(OutputRegion::new(
+            left,
+            top,
+            dst_image.width(),
+            dst_image.height(),
+            dst_width,
+            dst_height,
+        )?);
+        let result = self.resize(src_image, dst_image);
+        self.output_region = None;
+        result
+    }
+
+    /// Converts sRGB-encoded source image into linear `U16x4` image,
+    /// resizes it and encodes the result back into destination image.
+    fn resize_in_linear_light(
+        &mut self,
+        src_image: &ImageView<U8x4>,
+        dst_image: &mut ImageViewMut<U8x4>,
+    ) -> Result<(), ResizeError> {
+        let mapper = srgb_mapper();
+        let mut src_buffer = std::mem::take(&mut self.linear_src_buffer);
+        let mut dst_buffer = std::mem::take(&mut self.linear_dst_buffer);
+
+        let mut linear_src =
+            get_temp_image_from_buffer::<U16x4>(&mut src_buffer, src_image.width(), src_image.height());
+        let mut linear_src_view = linear_src.dst_view();
+        mapper.forward_map_u8x4(src_image, &mut linear_src_view, self.input_layout);
+
+        let mut linear_dst =
+            get_temp_image_from_buffer::<U16x4>(&mut dst_buffer, dst_image.width(), dst_image.height());
+        let mut linear_dst_view = linear_dst.dst_view();
+        let result = self.resize_with_alpha(&linear_src_view.into(), &mut linear_dst_view);
+        if result.is_ok() {
+            mapper.backward_map_u16x4(&linear_dst_view.into(), dst_image, self.output_layout);
+        }
+
+        self.linear_src_buffer = src_buffer;
+        self.linear_dst_buffer = dst_buffer;
+        result
+    }
+
+    fn resize_with_alpha<P>(
+        &mut self,
+        src_image: &ImageView<P>,
+        dst_image: &mut ImageViewMut<P>,
+    ) -> Result<(), ResizeError>
+    where
+        P: Convolution + Nearest + AlphaMulDiv,
+    {
+        if !self.blends_pixels() {
+            return self.resize_inner(src_image, dst_image);
+        }
+        if self.premultiplied_input {
+            self.resize_inner(src_image, dst_image)?;
+        } else {
+            let mut alpha_buffer = std::mem::take(&mut self.alpha_buffer);
+            let mut premultiplied = get_temp_image_from_buffer(
+                &mut alpha_buffer,
+                src_image.width(),
+                src_image.height(),
+            );
+            let mut premultiplied_view = premultiplied.dst_view();
+            P::multiply_alpha(src_image, &mut premultiplied_view, self.cpu_extensions);
+            let result = self.resize_inner(&premultiplied_view.into(), dst_image);
+            self.alpha_buffer = alpha_buffer;
+            result?;
+        }
+        if !self.premultiplied_output {
+            P::divide_alpha_inplace(dst_image, self.cpu_extensions);
+        }
+        Ok(())
+    }
+
+    /// Resizes images with packed pixels by unpacking them, resizing the unpacked
+    /// images with `resize_unpacked` and packing the result.
+    fn resize_packed<P, F>(
+        &mut self,
+        src_image: &ImageView<P>,
+        dst_image: &mut ImageViewMut<P>,
+        resize_unpacked: F,
+    ) -> Result<(), ResizeError>
+    where
+        P: PackedPixel + Nearest,
+        F: FnOnce(
+            &mut Self,
+            &ImageView<P::Unpacked>,
+            &mut ImageViewMut<P::Unpacked>,
+        ) -> Result<(), ResizeError>,
+    {
+        if !self.blends_pixels() {
+            // Pixels are copied, so there is no need to unpack them.
+            let crop_box = self.checked_crop_box(src_image.width(), src_image.height())?;
+            let region = self.output_region(dst_image);
+            P::resample_nearest(src_image, dst_image, crop_box, region, self.cpu_extensions);
+            return Ok(());
+        }
+        let mut src_buffer = std::mem::take(&mut self.unpacked_src_buffer);
+        let mut dst_buffer = std::mem::take(&mut self.unpacked_dst_buffer);
+
+        let mut unpacked_src =
+            get_temp_image_from_buffer(&mut src_buffer, src_image.width(), src_image.height());
+        let mut unpacked_src_view = unpacked_src.dst_view();
+        packed::unpack(src_image, &mut unpacked_src_view);
+
+        let mut unpacked_dst =
+            get_temp_image_from_buffer(&mut dst_buffer, dst_image.width(), dst_image.height());
+        let mut unpacked_dst_view = unpacked_dst.dst_view();
+        let result = resize_unpacked(self, &unpacked_src_view.into(), &mut unpacked_dst_view);
+        if result.is_ok() {
+            let region = self.output_region(dst_image);
+            packed::pack(&unpacked_dst_view.into(), dst_image, self.dithering, (region.left, region.top));
+        }
+
+        self.unpacked_src_buffer = src_buffer;
+        self.unpacked_dst_buffer = dst_buffer;
+        result
+    }
+
+    /// Resizes `U8x4` images whose alpha channel isn't the last component
+    /// or whose components have to be reordered.
+    fn resize_with_layouts(
+        &mut self,
+        src_image: &ImageView<U8x4>,
+        dst_image: &mut ImageViewMut<U8x4>,
+    ) -> Result<(), ResizeError> {
+        let (input, output) = (self.input_layout, self.output_layout);
+        if !self.blends_pixels() || self.premultiplied_input {
+            self.resize_inner(src_image, dst_image)?;
+        } else {
+            let mut alpha_buffer = std::mem::take(&mut self.alpha_buffer);
+            let mut premultiplied = get_temp_image_from_buffer(
+                &mut alpha_buffer,
+                src_image.width(),
+                src_image.height(),
+            );
+            let mut premultiplied_view = premultiplied.dst_view();
+            if input.alpha_index() == 3 {
+                U8x4::multiply_alpha(src_image, &mut premultiplied_view, self.cpu_extensions);
+            } else {
+                layout::multiply_alpha(src_image, &mut premultiplied_view, input);
             }
+            let result = self.resize_inner(&premultiplied_view.into(), dst_image);
+            self.alpha_buffer = alpha_buffer;
+            result?;
+        }
+        if self.blends_pixels() && !self.premultiplied_output {
+            layout::divide_alpha_inplace(dst_image, input, output);
+        } else {
+            layout::swizzle_inplace(dst_image, input, output);
         }
         Ok(())
     }
 
-    fn resize_inner<P>(&mut self, src_image: &ImageView<P>, dst_image: &mut ImageViewMut<P>)
+    /// Resizes the luma plane and the two chroma planes of a YUV 4:2:0 image
+    /// (e.g. I420 or YV12).
+    ///
+    /// Chroma planes must have half the dimensions of luma plane, rounded up.
+    /// Their samples are expected to be horizontally co-sited with the even
+    /// luma samples and vertically centered between two luma rows (the default
+    /// of MPEG-2, H.264 and later codecs), and the destination keeps this siting.
+    /// The crop box is given in luma pixels.
+    pub fn resize_yuv420(
+        &mut self,
+        [src_y, src_u, src_v]: [&ImageView<U8>; 3],
+        [dst_y, dst_u, dst_v]: [&mut ImageViewMut<U8>; 3],
+    ) -> Result<(), ResizeError> {
+        let src_luma_size = (src_y.width(), src_y.height());
+        let dst_luma_size = (dst_y.width(), dst_y.height());
+        for (src, dst) in [(src_u, &*dst_u), (src_v, &*dst_v)] {
+            check_chroma_420_size(src_luma_size, (src.width(), src.height()))?;
+            check_chroma_420_size(dst_luma_size, (dst.width(), dst.height()))?;
+        }
+        self.resize_inner(src_y, dst_y)?;
+        self.resize_chroma_420(src_luma_size, dst_luma_size, src_u, dst_u)?;
+        self.resize_chroma_420(src_luma_size, dst_luma_size, src_v, dst_v)
+    }
+
+    /// Resizes the luma plane and the interleaved chroma plane of a semi-planar
+    /// YUV 4:2:0 image (e.g. NV12, or NV21 with V before U).
+    ///
+    /// Chroma plane must have half the dimensions of luma plane, rounded up,
+    /// and the same siting as for [Resizer::resize_yuv420]. Its second component
+    /// is a color component, so it is never treated as alpha channel.
+    pub fn resize_yuv420_semi_planar(
+        &mut self,
+        src_y: &ImageView<U8>,
+        src_uv: &ImageView<U8x2>,
+        dst_y: &mut ImageViewMut<U8>,
+        dst_uv: &mut ImageViewMut<U8x2>,
+    ) -> Result<(), ResizeError> {
+        let src_luma_size = (src_y.width(), src_y.height());
+        let dst_luma_size = (dst_y.width(), dst_y.height());
+        check_chroma_420_size(src_luma_size, (src_uv.width(), src_uv.height()))?;
+        check_chroma_420_size(dst_luma_size, (dst_uv.width(), dst_uv.height()))?;
+        self.resize_inner(src_y, dst_y)?;
+        self.resize_chroma_420(src_luma_size, dst_luma_size, src_uv, dst_uv)
+    }
+
+    /// Resizes the pixels of type `P` stored in the supplied buffer
+    /// into a new buffer.
+    fn resize_buffer<P>(
+        &mut self,
+        src: &[u8],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u8>, ResizeError>
+    where
+        P: PixelExt,
+        for<'a> ImageView<'a, P>: Into<DynamicImageView<'a>>,
+        for<'a> ImageViewMut<'a, P>: Into<DynamicImageViewMut<'a>>,
+    {
+        // Validate everything before allocating anything.
+        let pixel_type = P::pixel_type();
+        pixel_type.buffer_size(input_width, input_height)?;
+        let output_size = pixel_type.buffer_size(output_width, output_height)?;
+        let mut dst = vec![0; output_size];
+        self.resize_buffers::<P>(src, input_width, input_height, &mut dst, output_width, output_height)?;
+        Ok(dst)
+    }
+
+    /// Resizes the pixels of type `P` stored in the supplied buffer of
+    /// their components into a new buffer.
+    fn resize_components<P, C>(
+        &mut self,
+        src: &[C],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<C>, ResizeError>
+    where
+        P: PixelExt<Component = C>,
+        C: Copy + Default,
+        for<'a> ImageView<'a, P>: Into<DynamicImageView<'a>>,
+        for<'a> ImageViewMut<'a, P>: Into<DynamicImageViewMut<'a>>,
+    {
+        let pixel_type = P::pixel_type();
+        pixel_type.buffer_size(input_width, input_height)?;
+        let output_size = pixel_type.buffer_size(output_width, output_height)?;
+        let mut dst = vec![C::default(); output_size / size_of::<C>()];
+        // Reinterpreting components as bytes always succeeds.
+        let (_, src_bytes, _) = unsafe { src.align_to::<u8>() };
+        let (_, dst_bytes, _) = unsafe { dst.align_to_mut::<u8>() };
+        self.resize_buffers::<P>(src_bytes, input_width, input_height, dst_bytes, output_width, output_height)?;
+        Ok(dst)
+    }
+
+    /// Resizes the pixels of type `P` stored in the supplied source buffer
+    /// into the supplied destination buffer.
+    fn resize_buffers<P>(
+        &mut self,
+        src: &[u8],
+        input_width: usize,
+        input_height: usize,
+        dst: &mut [u8],
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<(), ResizeError>
+    where
+        P: PixelExt,
+        for<'a> ImageView<'a, P>: Into<DynamicImageView<'a>>,
+        for<'a> ImageViewMut<'a, P>: Into<DynamicImageViewMut<'a>>,
+    {
+        let pixel_type = P::pixel_type();
+        if src.len() != pixel_type.buffer_size(input_width, input_height)?
+            || dst.len() != pixel_type.buffer_size(output_width, output_height)?
+        {
+            return Err(ImageBufferError::InvalidBufferSize.into());
+        }
+        let src_view = ImageView::<P>::from_buffer(input_width, input_height, src)?;
+        let dst_view = ImageViewMut::<P>::from_buffer(output_width, output_height, dst)?;
+        self.resize(&src_view.into(), &mut dst_view.into())
+    }
+
+    /// Returns `false` if destination pixels are copies of source pixels,
+    /// so that neither alpha nor gamma have to be taken into account.
+    fn blends_pixels(&self) -> bool {
+        !matches!(self.algorithm, ResizeAlg::Nearest)
+    }
+
+    fn resize_inner<P>(
+        &mut self,
+        src_image: &ImageView<P>,
+        dst_image: &mut ImageViewMut<P>,
+    ) -> Result<(), ResizeError>
+    where
+        P: Convolution + Nearest,
+    {
+        let crop_box = self.checked_crop_box(src_image.width(), src_image.height())?;
+        self.resize_cropped(src_image, dst_image, crop_box)
+    }
+
+    /// Returns the crop box of a source image with given dimensions.
+    fn checked_crop_box(&self, src_width: usize, src_height: usize) -> Result<CropBox, ResizeError> {
+        match self.crop_box {
+            Some(crop_box) => {
+                crop_box.check(src_width, src_height)?;
+                Ok(crop_box)
+            }
+            None => Ok(CropBox::whole(src_width, src_height)),
+        }
+    }
+
+    fn resize_cropped<P>(
+        &mut self,
+        src_image: &ImageView<P>,
+        dst_image: &mut ImageViewMut<P>,
+        crop_box: CropBox,
+    ) -> Result<(), ResizeError>
     where
-        P: Convolution,
+        P: Convolution + Nearest,
     {
+        let region = self.output_region(dst_image);
         match self.algorithm {
+            ResizeAlg::Nearest => {
+                P::resample_nearest(src_image, dst_image, crop_box, region, self.cpu_extensions)
+            }
             ResizeAlg::Convolution(filter_type) => {
-                let convolution_buffer = &mut self.convolution_buffer;
-                resample_convolution(
-                    src_image,
-                    dst_image,
-                    filter_type,
-                    self.cpu_extensions,
-                    convolution_buffer,
-                )
+                self.resize_convolution(src_image, dst_image, crop_box, region, filter_type)
+            }
+            ResizeAlg::SuperSampling(_, 0) => return Err(ResizeError::ZeroMultiplicity),
+            ResizeAlg::SuperSampling(filter_type, multiplicity) => {
+                let multiplicity = multiplicity as f64;
+                let max_width = region.full_width as f64 * multiplicity;
+                let max_height = region.full_height as f64 * multiplicity;
+                if crop_box.width > max_width || crop_box.height > max_height {
+                    // The reduced image keeps the fractional part of the crop box,
+                    // so that its last pixels are partially used by the filter.
+                    let reduced_box = CropBox {
+                        left: 0.0,
+                        top: 0.0,
+                        width: crop_box.width.min(max_width),
+                        height: crop_box.height.min(max_height),
+                    };
+                    let width = reduced_box.width.ceil() as usize;
+                    let height = reduced_box.height.ceil() as usize;
+                    // Only the reduced rows used by the region are sampled.
+                    let dst_rows = region.top..region.top + dst_image.height();
+                    let rows = match vert_coefficients(
+                        &mut self.vert_coeffs,
+                        height,
+                        reduced_box,
+                        region.full_height,
+                        filter_type,
+                    ) {
+                        Some(coeffs) => used_rows(&coeffs.bounds[dst_rows]),
+                        None => dst_rows.start as u32..dst_rows.end as u32,
+                    };
+                    let (y_first, y_end) = (rows.start as usize, rows.end as usize);
+                    let mut buffer = std::mem::take(&mut self.super_sampling_buffer);
+                    let mut reduced = get_temp_image_from_buffer(&mut buffer, width, y_end - y_first);
+                    let mut reduced_view = reduced.dst_view();
+                    // Every pixel of the reduced image, including the partial ones,
+                    // covers the same part of the crop box.
+                    let sampled_box = CropBox {
+                        width: crop_box.width * width as f64 / reduced_box.width,
+                        height: crop_box.height * height as f64 / reduced_box.height,
+                        ..crop_box
+                    };
+                    P::resample_nearest(
+                        src_image,
+                        &mut reduced_view,
+                        sampled_box,
+                        OutputRegion {
+                            left: 0,
+                            top: y_first,
+                            full_width: width,
+                            full_height: height,
+                        },
+                        self.cpu_extensions,
+                    );
+                    let reduced_view = ImageView::from(reduced_view)
+                        .with_empty_rows_before(y_first)
+                        .with_empty_rows_after(height - y_end);
+                    self.resize_convolution(&reduced_view, dst_image, reduced_box, region, filter_type);
+                    self.super_sampling_buffer = buffer;
+                } else {
+                    self.resize_convolution(src_image, dst_image, crop_box, region, filter_type)
+                }
             }
         }
+        Ok(())
+    }
+
+    /// Returns the region of the full destination image given by destination
+    /// image, which is the whole image unless [Resizer::resize_region] is called.
+    fn output_region<P: PixelExt>(&self, dst_image: &ImageViewMut<P>) -> OutputRegion {
+        self.output_region
+            .unwrap_or_else(|| OutputRegion::whole(dst_image.width(), dst_image.height()))
+    }
+
+    /// Resizes a chroma plane of a YUV 4:2:0 image along with its luma plane,
+    /// whose dimensions are given.
+    /// The dimensions of the planes must be checked with [check_chroma_420_size].
+    fn resize_chroma_420<P>(
+        &mut self,
+        src_luma_size: (usize, usize),
+        dst_luma_size: (usize, usize),
+        src_image: &ImageView<P>,
+        dst_image: &mut ImageViewMut<P>,
+    ) -> Result<(), ResizeError>
+    where
+        P: Convolution + Nearest,
+    {
+        let crop_box = self.checked_crop_box(src_luma_size.0, src_luma_size.1)?.chroma_420(
+            dst_luma_size.0,
+            dst_luma_size.1,
+            dst_image.width(),
+            dst_image.height(),
+        );
+        self.resize_cropped(src_image, dst_image, crop_box)
+    }
+
+    fn resize_convolution<P: Convolution>(
+        &mut self,
+        src_image: &ImageView<P>,
+        dst_image: &mut ImageViewMut<P>,
+        crop_box: CropBox,
+        region: OutputRegion,
+        filter_type: FilterType,
+    ) {
+        resample_convolution(
+            src_image,
+            dst_image,
+            crop_box,
+            region,
+            filter_type,
+            self.cpu_extensions,
+            &mut self.convolution_buffer,
+            [&mut self.horiz_coeffs, &mut self.vert_coeffs],
+        )
     }
 }
 
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl Resizer {
+    /// Creates instance of `Resizer` using the algorithm with given name
+    /// (see [ResizeAlg::from_str]).
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn with_algorithm(algorithm: &str) -> Result<Resizer, ResizeError> {
+        Ok(Self::new(algorithm.parse()?))
+    }
+
+    /// Declares that source images are already multiplied by alpha channel.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = setPremultipliedInput))]
+    pub fn set_premultiplied_input(&mut self, premultiplied: bool) {
+        self.premultiplied_input = premultiplied;
+    }
+
+    /// Keeps destination images multiplied by alpha channel
+    /// (e.g. for WebGL uploads), instead of dividing them.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = setPremultipliedOutput))]
+    pub fn set_premultiplied_output(&mut self, premultiplied: bool) {
+        self.premultiplied_output = premultiplied;
+    }
+
+    /// Dithers the components of packed pixels (`Rgb565` and `Rgba4444`) with
+    /// an ordered pattern instead of rounding them, which avoids banding
+    /// in smooth gradients.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = setDithering))]
+    pub fn set_dithering(&mut self, dithering: bool) {
+        self.dithering = dithering;
+    }
+
+    /// Sets the layouts of the source and destination rgba buffers by name
+    /// (`rgba`, `bgra`, `argb` or `abgr`, see [Resizer::set_pixel_layouts]).
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = setPixelLayout))]
+    pub fn set_pixel_layout(&mut self, input: &str, output: &str) -> Result<(), ResizeError> {
+        self.set_pixel_layouts(input.parse()?, output.parse()?);
+        Ok(())
+    }
+
+    /// Resizes sRGB-encoded images in linear light, which keeps the brightness
+    /// of high-contrast details such as text.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = setLinearLight))]
+    pub fn set_linear_light(&mut self, linear_light: bool) {
+        self.linear_light = linear_light;
+    }
+
+    /// Sets the sub-rectangle of the source image that is resized by the next calls.
+    /// Fractional values are allowed.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = setCrop))]
+    pub fn set_crop(&mut self, left: f64, top: f64, width: f64, height: f64) {
+        self.set_crop_box(Some(CropBox {
+            left,
+            top,
+            width,
+            height,
+        }));
+    }
+
+    /// Removes the crop box, so that the next calls resize the whole source image.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = clearCrop))]
+    pub fn clear_crop(&mut self) {
+        self.set_crop_box(None);
+    }
+
+    /// Resizes the supplied rgba buffer.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resize))]
+    pub fn resize_rgba(
+        &mut self,
+        rgba: &[u8],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u8>, ResizeError> {
+        self.resize_buffer::<U8x4>(rgba, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied rgba buffer into the supplied destination buffer.
+    ///
+    /// Nothing is allocated for the result.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeInto))]
+    pub fn resize_rgba_into(
+        &mut self,
+        src: &[u8],
+        input_width: usize,
+        input_height: usize,
+        dst: &mut [u8],
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<(), ResizeError> {
+        self.resize_buffers::<U8x4>(src, input_width, input_height, dst, output_width, output_height)
+    }
+
+    /// Resizes the supplied rgba buffer, but computes only the region of the
+    /// result with dimensions `width` x `height` which starts at `left` x `top`.
+    /// Returns an error if the region is outside of the result.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRegion))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn resize_rgba_region(
+        &mut self,
+        rgba: &[u8],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+        left: usize,
+        top: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<Vec<u8>, ResizeError> {
+        let pixel_type = PixelType::U8x4;
+        if rgba.len() != pixel_type.buffer_size(input_width, input_height)? {
+            return Err(ImageBufferError::InvalidBufferSize.into());
+        }
+        pixel_type.buffer_size(output_width, output_height)?;
+        let size = pixel_type.buffer_size(width, height)?;
+        OutputRegion::new(left, top, width, height, output_width, output_height)?;
+        let mut dst = vec![0; size];
+        let src_view = ImageView::<U8x4>::from_buffer(input_width, input_height, rgba)?;
+        let dst_view = ImageViewMut::<U8x4>::from_buffer(width, height, &mut dst)?;
+        self.resize_region(
+            &src_view.into(),
+            &mut dst_view.into(),
+            output_width,
+            output_height,
+            left,
+            top,
+        )?;
+        Ok(dst)
+    }
+
+    /// Resizes the supplied grayscale buffer (one byte per pixel).
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeGray))]
+    pub fn resize_gray(
+        &mut self,
+        gray: &[u8],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u8>, ResizeError> {
+        self.resize_buffer::<U8>(gray, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied grayscale buffer with alpha channel
+    /// (two bytes per pixel, luma then alpha).
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeGrayAlpha))]
+    pub fn resize_gray_alpha(
+        &mut self,
+        gray_alpha: &[u8],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u8>, ResizeError> {
+        self.resize_buffer::<U8x2>(gray_alpha, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied packed rgb buffer (three bytes per pixel).
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgb))]
+    pub fn resize_rgb(
+        &mut self,
+        rgb: &[u8],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u8>, ResizeError> {
+        self.resize_buffer::<U8x3>(rgb, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied rgba buffer with 16 bits per channel.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgbaU16))]
+    pub fn resize_rgba_u16(
+        &mut self,
+        rgba: &[u16],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u16>, ResizeError> {
+        self.resize_components::<U16x4, u16>(rgba, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied RGB565 buffer (one u16 per pixel).
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgb565))]
+    pub fn resize_rgb565(
+        &mut self,
+        rgb565: &[u16],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u16>, ResizeError> {
+        self.resize_components::<Rgb565, u16>(rgb565, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied RGBA4444 buffer (one u16 per pixel).
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgba4444))]
+    pub fn resize_rgba4444(
+        &mut self,
+        rgba4444: &[u16],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u16>, ResizeError> {
+        self.resize_components::<Rgba4444, u16>(rgba4444, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied grayscale buffer of floats (one per pixel).
+    /// Values are neither clamped nor rounded.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeGrayF32))]
+    pub fn resize_gray_f32(
+        &mut self,
+        gray: &[f32],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<f32>, ResizeError> {
+        self.resize_components::<F32, f32>(gray, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied rgba buffer of floats (four per pixel).
+    /// Values are neither clamped nor rounded.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeRgbaF32))]
+    pub fn resize_rgba_f32(
+        &mut self,
+        rgba: &[f32],
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<f32>, ResizeError> {
+        self.resize_components::<F32x4, f32>(rgba, input_width, input_height, output_width, output_height)
+    }
+
+    /// Resizes the supplied Y, U and V planes of an I420 image, each with
+    /// its own stride in bytes, and returns the resized planes one after
+    /// another without padding (the layout of an I420 `VideoFrame`).
+    ///
+    /// Chroma planes have half the dimensions of luma plane, rounded up
+    /// (see [Resizer::resize_yuv420] for the chroma siting).
+    /// Returns an error if a plane doesn't fit into its buffer with its stride.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeI420))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn resize_i420(
+        &mut self,
+        y: &[u8],
+        y_stride: usize,
+        u: &[u8],
+        u_stride: usize,
+        v: &[u8],
+        v_stride: usize,
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u8>, ResizeError> {
+        let src_chroma_size = chroma_420_size((input_width, input_height));
+        let dst_chroma_size = chroma_420_size((output_width, output_height));
+        PixelType::U8.buffer_size(input_width, input_height)?;
+        let luma_size = PixelType::U8.buffer_size(output_width, output_height)?;
+        let chroma_size = PixelType::U8.buffer_size(dst_chroma_size.0, dst_chroma_size.1)?;
+
+        let src_y = ImageView::<U8>::from_buffer_with_stride(input_width, input_height, y, y_stride)?;
+        let src_u = ImageView::<U8>::from_buffer_with_stride(src_chroma_size.0, src_chroma_size.1, u, u_stride)?;
+        let src_v = ImageView::<U8>::from_buffer_with_stride(src_chroma_size.0, src_chroma_size.1, v, v_stride)?;
+
+        let mut dst = vec![0; luma_size + 2 * chroma_size];
+        let (dst_y, dst_chroma) = dst.split_at_mut(luma_size);
+        let (dst_u, dst_v) = dst_chroma.split_at_mut(chroma_size);
+        let mut dst_y = ImageViewMut::<U8>::from_buffer(output_width, output_height, dst_y)?;
+        let mut dst_u = ImageViewMut::<U8>::from_buffer(dst_chroma_size.0, dst_chroma_size.1, dst_u)?;
+        let mut dst_v = ImageViewMut::<U8>::from_buffer(dst_chroma_size.0, dst_chroma_size.1, dst_v)?;
+        self.resize_yuv420([&src_y, &src_u, &src_v], [&mut dst_y, &mut dst_u, &mut dst_v])?;
+        Ok(dst)
+    }
+
+    /// Resizes the supplied Y and interleaved UV planes of an NV12 image, each
+    /// with its own stride in bytes, and returns the resized planes one after
+    /// another without padding (the layout of an NV12 `VideoFrame`).
+    ///
+    /// Chroma plane has half the dimensions of luma plane, rounded up
+    /// (see [Resizer::resize_yuv420] for the chroma siting).
+    /// Returns an error if a plane doesn't fit into its buffer with its stride,
+    /// or if the stride of the chroma plane is odd.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeNv12))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn resize_nv12(
+        &mut self,
+        y: &[u8],
+        y_stride: usize,
+        uv: &[u8],
+        uv_stride: usize,
+        input_width: usize,
+        input_height: usize,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<Vec<u8>, ResizeError> {
+        let src_chroma_size = chroma_420_size((input_width, input_height));
+        let dst_chroma_size = chroma_420_size((output_width, output_height));
+        PixelType::U8.buffer_size(input_width, input_height)?;
+        let luma_size = PixelType::U8.buffer_size(output_width, output_height)?;
+        let chroma_size = PixelType::U8x2.buffer_size(dst_chroma_size.0, dst_chroma_size.1)?;
+
+        let src_y = ImageView::<U8>::from_buffer_with_stride(input_width, input_height, y, y_stride)?;
+        let src_uv = ImageView::<U8x2>::from_buffer_with_stride(src_chroma_size.0, src_chroma_size.1, uv, uv_stride)?;
+
+        let mut dst = vec![0; luma_size + chroma_size];
+        let (dst_y, dst_uv) = dst.split_at_mut(luma_size);
+        let mut dst_y = ImageViewMut::<U8>::from_buffer(output_width, output_height, dst_y)?;
+        let mut dst_uv = ImageViewMut::<U8x2>::from_buffer(dst_chroma_size.0, dst_chroma_size.1, dst_uv)?;
+        self.resize_yuv420_semi_planar(&src_y, &src_uv, &mut dst_y, &mut dst_uv)?;
+        Ok(dst)
+    }
+
+    /// Allocates (or reuses) a region of memory for a source rgba image
+    /// of the given dimensions and returns a pointer to it.
+    ///
+    /// The region is kept between calls, so that javascript can write the
+    /// pixels directly into it before calling [Resizer::resize_source_buffer].
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = sourceBuffer))]
+    pub fn source_buffer(&mut self, width: usize, height: usize) -> Result<*mut u8, ResizeError> {
+        let size = PixelType::U8x4.buffer_size(width, height)?;
+        self.source_buffer.resize(size, 0);
+        self.source_size = (width, height);
+        Ok(self.source_buffer.as_mut_ptr())
+    }
+
+    /// Resizes the image stored in the source buffer into a destination
+    /// buffer, which is also kept between calls, and returns a pointer to the latter.
+    ///
+    /// Returns [ResizeError::NoSourceBuffer] if [Resizer::source_buffer]
+    /// hasn't been called before.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = resizeSourceBuffer))]
+    pub fn resize_source_buffer(
+        &mut self,
+        output_width: usize,
+        output_height: usize,
+    ) -> Result<*const u8, ResizeError> {
+        if self.source_buffer.is_empty() {
+            return Err(ResizeError::NoSourceBuffer);
+        }
+        let (input_width, input_height) = self.source_size;
+        PixelType::U8x4.buffer_size(input_width, input_height)?;
+        let output_size = PixelType::U8x4.buffer_size(output_width, output_height)?;
+        let src = std::mem::take(&mut self.source_buffer);
+        let mut dst = std::mem::take(&mut self.destination_buffer);
+        dst.resize(output_size, 0);
+        let result = self.resize_rgba_into(
+            &src,
+            input_width,
+            input_height,
+            &mut dst,
+            output_width,
+            output_height,
+        );
+        self.source_buffer = src;
+        self.destination_buffer = dst;
+        result.map(|_| self.destination_buffer.as_ptr())
+    }
+}
+
+/// Dimensions of a chroma plane of a YUV 4:2:0 image with given luma dimensions.
+fn chroma_420_size((width, height): (usize, usize)) -> (usize, usize) {
+    (width.div_ceil(2), height.div_ceil(2))
+}
+
+fn check_chroma_420_size(luma_size: (usize, usize), chroma_size: (usize, usize)) -> Result<(), ResizeError> {
+    if chroma_size == chroma_420_size(luma_size) {
+        Ok(())
+    } else {
+        Err(ResizeError::InvalidChromaDimensions)
+    }
+}
+
+/// Number of coefficients kept for each direction, so that alternating sizes,
+/// like the luma and the chroma planes of a frame, are still reused.
+const CACHED_COEFFICIENTS: usize = 4;
+
+/// Coefficients of a previous call, reused while the sizes and the filter don't change.
+#[derive(Debug, Clone)]
+struct CachedCoefficients {
+    in_size: usize,
+    in0: f64,
+    in1: f64,
+    out_size: usize,
+    filter_type: FilterType,
+    coefficients: Coefficients,
+}
+
+/// Returns the cached coefficients, which are computed again only if
+/// the sizes or the filter differ from the recent calls.
+/// The least recently used coefficients are dropped when the cache is full.
+fn get_coefficients(
+    cache: &mut Vec<CachedCoefficients>,
+    in_size: usize,
+    in0: f64,
+    in1: f64,
+    out_size: usize,
+    filter_type: FilterType,
+) -> &Coefficients {
+    let position = cache.iter().position(|cached| {
+        cached.in_size == in_size
+            && cached.in0 == in0
+            && cached.in1 == in1
+            && cached.out_size == out_size
+            && cached.filter_type == filter_type
+    });
+    let cached = match position {
+        Some(index) => cache.remove(index),
+        None => {
+            if cache.len() == CACHED_COEFFICIENTS {
+                cache.remove(0);
+            }
+            let (filter_fn, filter_support) = convolution::get_filter_func(filter_type);
+            let coefficients = convolution::precompute_coefficients(
+                in_size,
+                in0,
+                in1,
+                out_size,
+                filter_fn,
+                filter_support,
+            );
+            CachedCoefficients {
+                in_size,
+                in0,
+                in1,
+                out_size,
+                filter_type,
+                coefficients,
+            }
+        }
+    };
+    cache.push(cached);
+    &cache.last().unwrap().coefficients
+}
+
 /// Create inner image container from part of given buffer.
 /// Buffer may be expanded if it size is less than required for image.
 fn get_temp_image_from_buffer<P: PixelExt>(
     buffer: &mut Vec<u8>,
     width: usize,
     height: usize,
-) -> InnerImage<P> {
+) -> InnerImage<'_, P> {
     let pixels_count = width * height;
     // Add pixel size as gap for alignment of resulted buffer.
     let buf_size = pixels_count * P::size() + P::size();
@@ -119,12 +1313,16 @@ fn get_temp_image_from_buffer<P: PixelExt>(
     InnerImage::new(width, height, &mut pixels[0..pixels_count])
 }
 
+#[allow(clippy::too_many_arguments)]
 fn resample_convolution<P>(
     src_image: &ImageView<P>,
     dst_image: &mut ImageViewMut<P>,
+    crop_box: CropBox,
+    region: OutputRegion,
     filter_type: FilterType,
     cpu_extensions: CpuExtensions,
     temp_buffer: &mut Vec<u8>,
+    [horiz_cache, vert_cache]: [&mut Vec<CachedCoefficients>; 2],
 ) where
     P: Convolution,
 {
@@ -132,80 +1330,580 @@ fn resample_convolution<P>(
     let src_height = src_image.height();
     let dst_width = dst_image.width();
     let dst_height = dst_image.height();
-    let (filter_fn, filter_support) = convolution::get_filter_func(filter_type);
 
-    let need_horizontal = dst_width != src_width;
+    let need_horizontal = region.full_width != src_width
+        || crop_box.left != 0.0
+        || crop_box.width != src_width as f64;
     let horiz_coeffs = need_horizontal.then(|| {
-        convolution::precompute_coefficients(
+        let coeffs = get_coefficients(
+            horiz_cache,
             src_width,
-            0.0,
-            src_width as f64,
-            dst_width,
-            filter_fn,
-            filter_support,
-        )
+            crop_box.left,
+            crop_box.left + crop_box.width,
+            region.full_width,
+            filter_type,
+        );
+        if dst_width == region.full_width {
+            Cow::Borrowed(coeffs)
+        } else {
+            Cow::Owned(coeffs.slice(region.left..region.left + dst_width))
+        }
     });
 
-    let need_vertical = dst_height != src_height;
-    let vert_coeffs = need_vertical.then(|| {
-        convolution::precompute_coefficients(
-            src_image.height(),
-            0.0,
-            src_height as f64,
-            dst_height,
-            filter_fn,
-            filter_support,
-        )
-    });
+    let vert_coeffs = vert_coefficients(
+        vert_cache,
+        src_height,
+        crop_box,
+        region.full_height,
+        filter_type,
+    );
 
+    // Every band of destination rows is resized independently of the others,
+    // in parallel if `rayon` feature is enabled.
+    let bands = row_bands(dst_height);
+    let dst_bands = dst_image.split_by_heights(bands.iter().map(|band| band.len()));
     match (horiz_coeffs, vert_coeffs) {
-        (Some(horiz_coeffs), Some(mut vert_coeffs)) => {
-            let y_first = vert_coeffs.bounds[0].start;
-            // Last used row in the source image
-            let last_y_bound = vert_coeffs.bounds.last().unwrap();
-            let y_last = last_y_bound.start + last_y_bound.size;
-            let temp_height = y_last - y_first;
-            let mut temp_image = get_temp_image_from_buffer(temp_buffer, dst_width, temp_height as usize);
-            let mut tmp_dst_view = temp_image.dst_view();
-            P::horiz_convolution(
-                src_image,
-                &mut tmp_dst_view,
-                y_first,
-                horiz_coeffs,
-                cpu_extensions,
-            );
-
-            // Shift bounds for vertical pass
-            vert_coeffs
-                .bounds
-                .iter_mut()
-                .for_each(|b| b.start -= y_first);
-            P::vert_convolution(
-                &tmp_dst_view.into(),
-                dst_image,
-                0,
-                vert_coeffs,
-                cpu_extensions,
-            );
+        (Some(horiz_coeffs), Some(vert_coeffs)) => {
+            let vert_bands = split_coefficients(vert_coeffs, &bands, region.top);
+            // Every band has its own part of the temp buffer, so the source rows
+            // shared by neighbouring bands are convolved horizontally more than once.
+            let temp_heights: Vec<usize> = vert_bands
+                .iter()
+                .map(|coeffs| used_rows(&coeffs.bounds).len())
+                .collect();
+            let mut temp_image =
+                get_temp_image_from_buffer(temp_buffer, dst_width, temp_heights.iter().sum());
+            let mut temp_view = temp_image.dst_view();
+            let temp_bands = temp_view.split_by_heights(temp_heights);
+            let items = dst_bands.into_iter().zip(temp_bands).zip(vert_bands);
+            for_each_band(items.collect(), |((mut dst_band, temp_band), vert_coeffs)| {
+                resample_two_passes(
+                    src_image,
+                    &mut dst_band,
+                    temp_band,
+                    &horiz_coeffs,
+                    &vert_coeffs,
+                    cpu_extensions,
+                );
+            });
         }
         (Some(horiz_coeffs), None) => {
-            P::horiz_convolution(
-                src_image,
-                dst_image,
-                0,
-                horiz_coeffs,
-                cpu_extensions,
-            );
+            let items = dst_bands.into_iter().zip(bands);
+            for_each_band(items.collect(), |(mut dst_band, band)| {
+                P::horiz_convolution(
+                    src_image,
+                    &mut dst_band,
+                    (region.top + band.start) as u32,
+                    &horiz_coeffs,
+                    cpu_extensions,
+                );
+            });
         }
         (None, Some(vert_coeffs)) => {
-            P::vert_convolution(
-                src_image,
-                dst_image,
-                0,
-                vert_coeffs,
-                cpu_extensions,
+            let vert_bands = split_coefficients(vert_coeffs, &bands, region.top);
+            let items = dst_bands.into_iter().zip(vert_bands);
+            for_each_band(items.collect(), |(mut dst_band, vert_coeffs)| {
+                P::vert_convolution(
+                    src_image,
+                    &mut dst_band,
+                    region.left as u32,
+                    &vert_coeffs,
+                    cpu_extensions,
+                );
+            });
+        }
+        (None, None) => {
+            let src_rows = src_image.iter_rows(region.top as u32);
+            for (src_row, dst_row) in src_rows.zip(dst_image.iter_rows_mut()) {
+                dst_row.copy_from_slice(&src_row[region.left..region.left + dst_width]);
+            }
+        }
+    }
+}
+
+/// Returns vertical coefficients of a resize of crop box of an image with
+/// `src_height` rows into `dst_height` rows, or `None` if the rows are the same.
+fn vert_coefficients(
+    cache: &mut Vec<CachedCoefficients>,
+    src_height: usize,
+    crop_box: CropBox,
+    dst_height: usize,
+    filter_type: FilterType,
+) -> Option<&Coefficients> {
+    let need_vertical = dst_height != src_height
+        || crop_box.top != 0.0
+        || crop_box.height != src_height as f64;
+    need_vertical.then(|| {
+        get_coefficients(
+            cache,
+            src_height,
+            crop_box.top,
+            crop_box.top + crop_box.height,
+            dst_height,
+            filter_type,
+        )
+    })
+}
+
+/// Convolves the source rows used by `vert_coeffs` horizontally into
+/// the temp image, and then the latter vertically into destination image.
+fn resample_two_passes<P: Convolution>(
+    src_image: &ImageView<P>,
+    dst_image: &mut ImageViewMut<P>,
+    mut temp_image: ImageViewMut<P>,
+    horiz_coeffs: &Coefficients,
+    vert_coeffs: &Coefficients,
+    cpu_extensions: CpuExtensions,
+) {
+    let y_first = used_rows(&vert_coeffs.bounds).start;
+    P::horiz_convolution(
+        src_image,
+        &mut temp_image,
+        y_first,
+        horiz_coeffs,
+        cpu_extensions,
+    );
+
+    // The temp image only has the used rows, so the rows before them are
+    // left empty to keep the indexes of the vertical coefficients.
+    let temp_view = ImageView::from(temp_image).with_empty_rows_before(y_first as usize);
+    P::vert_convolution(
+        &temp_view,
+        dst_image,
+        0,
+        vert_coeffs,
+        cpu_extensions,
+    );
+}
+
+/// Rows of the source image used by bounds of vertical coefficients.
+fn used_rows(bounds: &[Bound]) -> Range<u32> {
+    let y_first = bounds[0].start;
+    let last_y_bound = bounds.last().unwrap();
+    y_first..last_y_bound.start + last_y_bound.size
+}
+
+/// Splits destination rows into bands processed by separate threads.
+#[cfg(feature = "rayon")]
+fn row_bands(height: usize) -> Vec<Range<usize>> {
+    let band_height = height.div_ceil(rayon::current_num_threads()).max(1);
+    (0..height)
+        .step_by(band_height)
+        .map(|y| y..(y + band_height).min(height))
+        .collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+#[allow(clippy::single_range_in_vec_init)]
+fn row_bands(height: usize) -> Vec<Range<usize>> {
+    vec![0..height]
+}
+
+/// Returns vertical coefficients of every band of destination rows, whose
+/// indexes are shifted by `offset` in the coefficients. The coefficients are
+/// borrowed if the only band covers them all.
+fn split_coefficients<'a>(
+    coeffs: &'a Coefficients,
+    bands: &[Range<usize>],
+    offset: usize,
+) -> Vec<Cow<'a, Coefficients>> {
+    if bands.len() == 1 && bands[0].len() == coeffs.bounds.len() {
+        return vec![Cow::Borrowed(coeffs)];
+    }
+    bands
+        .iter()
+        .map(|band| Cow::Owned(coeffs.slice(band.start + offset..band.end + offset)))
+        .collect()
+}
+
+#[cfg(feature = "rayon")]
+fn for_each_band<T: Send>(items: Vec<T>, f: impl Fn(T) + Send + Sync) {
+    use rayon::prelude::*;
+    items.into_par_iter().for_each(f);
+}
+
+#[cfg(not(feature = "rayon"))]
+fn for_each_band<T>(items: Vec<T>, f: impl Fn(T)) {
+    items.into_iter().for_each(f);
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+    use crate::test_utils::{gradient, noise, opaque_gradient, region_of};
+    use crate::pixels::U8;
+    use crate::{
+        resize, CpuExtensions, CropBox, FilterType, Image, ImageView, ImageViewMut, PixelLayout, PixelType, ResizeAlg,
+        ResizeError, Resizer,
+    };
+
+    #[test]
+    fn test_resizer_reuse() {
+        let data = gradient(64 * 48 * 4);
+        let mut resizer = Resizer::with_hq(true);
+        for (w, h) in [(20, 15), (20, 15), (33, 48), (64, 10), (20, 15)] {
+            assert_eq!(
+                resizer.resize_rgba(&data, 64, 48, w, h).unwrap(),
+                resize(&data, 64, 48, w, h, "catmullrom", false).unwrap()
             );
         }
-        _ => {}
+    }
+
+    #[test]
+    fn test_coefficients_cache() {
+        let data = gradient(64 * 48 * 4);
+        let mut resizer = Resizer::with_hq(true);
+        let cached_values = |resizer: &Resizer| {
+            [&resizer.horiz_coeffs, &resizer.vert_coeffs]
+                .map(|cache| cache.iter().map(|c| c.coefficients.values.as_ptr()).collect::<Vec<_>>())
+        };
+        // Alternating sizes, like the planes of a frame, reuse their coefficients.
+        resizer.resize_rgba(&data, 64, 48, 20, 15).unwrap();
+        resizer.resize_rgba(&data[..32 * 24 * 4], 32, 24, 10, 8).unwrap();
+        let cached = cached_values(&resizer);
+        assert_eq!(cached.each_ref().map(Vec::len), [2, 2]);
+        resizer.resize_rgba(&data, 64, 48, 20, 15).unwrap();
+        resizer.resize_rgba(&data[..32 * 24 * 4], 32, 24, 10, 8).unwrap();
+        assert_eq!(cached_values(&resizer), cached);
+
+        for width in 1..10 {
+            resizer.resize_rgba(&data, 64, 48, width, 15).unwrap();
+        }
+        assert_eq!(resizer.horiz_coeffs.len(), super::CACHED_COEFFICIENTS);
+    }
+
+    #[test]
+    fn test_resize_source_buffer() {
+        let data = gradient(64 * 48 * 4);
+        let mut resizer = Resizer::with_hq(false);
+        assert_eq!(resizer.resize_source_buffer(20, 15), Err(ResizeError::NoSourceBuffer));
+        let src = resizer.source_buffer(64, 48).unwrap();
+        unsafe { src.copy_from_nonoverlapping(data.as_ptr(), data.len()) };
+        let dst = resizer.resize_source_buffer(20, 15).unwrap();
+        let dst = unsafe { std::slice::from_raw_parts(dst, 20 * 15 * 4) };
+        assert_eq!(dst, resize(&data, 64, 48, 20, 15, "hamming", false).unwrap());
+        assert_eq!(resizer.source_buffer(0, 48), Err(ResizeError::ZeroDimensions));
+    }
+
+    #[test]
+    fn test_crop() {
+        let data = opaque_gradient(64 * 48 * 4);
+        assert_eq!(resize(&data, 64, 48, 64, 48, "catmullrom", false).unwrap(), data);
+
+        let mut resizer = Resizer::with_hq(true);
+        resizer.set_crop(3.0, 2.0, 10.0, 8.0);
+        let cropped = resizer.resize_rgba(&data, 64, 48, 10, 8).unwrap();
+        let expected: Vec<u8> = data
+            .chunks_exact(64 * 4)
+            .skip(2)
+            .take(8)
+            .flat_map(|row| row[3 * 4..13 * 4].iter().copied())
+            .collect();
+        assert_eq!(cropped, expected);
+
+        // Sub-pixel offsets change the result.
+        resizer.set_crop(3.5, 2.0, 10.0, 8.0);
+        assert_ne!(resizer.resize_rgba(&data, 64, 48, 10, 8).unwrap(), expected);
+
+        resizer.set_crop_box(Some(CropBox { left: 60.0, top: 0.0, width: 5.0, height: 8.0 }));
+        assert_eq!(
+            resizer.resize_rgba(&data, 64, 48, 10, 8),
+            Err(ResizeError::CropBoxOutOfBounds)
+        );
+        resizer.clear_crop();
+        assert_eq!(
+            resizer.resize_rgba(&data, 64, 48, 10, 8).unwrap(),
+            resize(&data, 64, 48, 10, 8, "catmullrom", false).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_yuv420_chroma_dimensions() {
+        let y = gradient(64 * 8);
+        let u = gradient(32 * 4);
+        let src = ImageView::<U8>::from_buffer(64, 8, &y).unwrap();
+        let src_chroma = ImageView::<U8>::from_buffer(16, 4, &u).unwrap();
+        let mut dst = [[0; 16 * 2]; 3];
+        let [dst_y, dst_u, dst_v] = &mut dst;
+        let mut dst_y = ImageViewMut::<U8>::from_buffer(16, 2, dst_y).unwrap();
+        let mut dst_u = ImageViewMut::<U8>::from_buffer(8, 1, dst_u).unwrap();
+        let mut dst_v = ImageViewMut::<U8>::from_buffer(8, 1, dst_v).unwrap();
+        assert_eq!(
+            Resizer::default().resize_yuv420(
+                [&src, &src_chroma, &src_chroma],
+                [&mut dst_y, &mut dst_u, &mut dst_v]
+            ),
+            Err(ResizeError::InvalidChromaDimensions)
+        );
+    }
+
+    #[test]
+    fn test_resize_alpha() {
+        // Opaque red next to a transparent black pixel.
+        let data = [255, 0, 0, 255, 0, 0, 0, 0];
+        let mut resizer = Resizer::with_hq(false);
+        let straight = resizer.resize_rgba(&data, 2, 1, 1, 1).unwrap();
+        assert_eq!(straight[0], 255);
+        assert!((127..=128).contains(&straight[3]));
+
+        resizer.set_premultiplied_output(true);
+        let premultiplied = resizer.resize_rgba(&data, 2, 1, 1, 1).unwrap();
+        assert_eq!(premultiplied[0], premultiplied[3]);
+
+        resizer.set_premultiplied_input(true);
+        resizer.set_premultiplied_output(false);
+        assert_eq!(resizer.resize_rgba(&premultiplied, 1, 1, 1, 1).unwrap(), straight);
+    }
+
+    #[test]
+    fn test_pixel_layouts() {
+        let rgba = gradient(64 * 48 * 4);
+        let to_layout = |rgba: &[u8], layout: PixelLayout| -> Vec<u8> {
+            let indexes = layout.indexes();
+            rgba.chunks_exact(4)
+                .flat_map(|pixel| {
+                    let mut res = [0; 4];
+                    for (c, &i) in indexes.iter().enumerate() {
+                        res[i] = pixel[c];
+                    }
+                    res
+                })
+                .collect()
+        };
+        let layouts = ["rgba", "bgra", "argb", "abgr"];
+        for algorithm in ["nearest", "lanczos3"] {
+            for (linear_light, premultiplied_output) in [(false, false), (false, true), (true, false)] {
+                let mut resizer = Resizer::with_algorithm(algorithm).unwrap();
+                resizer.set_linear_light(linear_light);
+                resizer.set_premultiplied_output(premultiplied_output);
+                let expected = resizer.resize_rgba(&rgba, 64, 48, 21, 13).unwrap();
+                for input in layouts {
+                    for output in layouts {
+                        resizer.set_pixel_layout(input, output).unwrap();
+                        let (input, output) = resizer.pixel_layouts();
+                        let src = to_layout(&rgba, input);
+                        let resized = resizer.resize_rgba(&src, 64, 48, 21, 13).unwrap();
+                        assert_eq!(resized, to_layout(&expected, output));
+                    }
+                }
+            }
+        }
+        let mut resizer = Resizer::with_algorithm("lanczos3").unwrap();
+        assert_eq!(resizer.set_pixel_layout("rgba", "rgb"), Err(ResizeError::UnknownPixelLayout));
+    }
+
+    #[test]
+    fn test_linear_light() {
+        // Black next to white.
+        let data = [0, 0, 0, 255, 255, 255, 255, 255];
+        let gamma = resize(&data, 2, 1, 1, 1, "hamming", false).unwrap();
+        let linear = resize(&data, 2, 1, 1, 1, "hamming", true).unwrap();
+        assert!((127..=128).contains(&gamma[0]));
+        assert!((186..=189).contains(&linear[0]));
+        assert_eq!(linear[3], 255);
+
+        // The mapping into linear light and back is lossless.
+        let data = opaque_gradient(64 * 48 * 4);
+        assert_eq!(resize(&data, 64, 48, 64, 48, "catmullrom", true).unwrap(), data);
+    }
+
+    #[test]
+    fn test_filters() {
+        let data = opaque_gradient(64 * 48 * 4);
+        for filter in ["box", "bilinear", "hamming", "CatmullRom", "mitchell", "lanczos3"] {
+            assert_eq!(resize(&data, 64, 48, 20, 15, filter, false).unwrap().len(), 20 * 15 * 4);
+        }
+        assert_eq!(
+            Resizer::with_algorithm("catmullrom").unwrap().resize_rgba(&data, 64, 48, 20, 15),
+            Resizer::with_hq(true).resize_rgba(&data, 64, 48, 20, 15)
+        );
+        assert_eq!(
+            resize(&data, 64, 48, 20, 15, "bicubic", false),
+            Err(ResizeError::UnknownFilterType)
+        );
+
+        // Box filter averages the pixels of each block.
+        let data = [10, 20, 30, 255, 30, 40, 50, 255];
+        assert_eq!(resize(&data, 2, 1, 1, 1, "box", false).unwrap(), [20, 30, 40, 255]);
+    }
+
+    #[test]
+    fn test_super_sampling() {
+        let data: Vec<u8> = (0..640 * 480 * 4)
+            .map(|i| if i % 4 == 3 { 255 } else { (i / 4 % 640 / 3) as u8 })
+            .collect();
+        let mut resizer = Resizer::with_algorithm("supersampling:lanczos3:2").unwrap();
+        assert!(matches!(
+            resizer.algorithm,
+            ResizeAlg::SuperSampling(FilterType::Lanczos3, 2)
+        ));
+        let sampled = resizer.resize_rgba(&data, 640, 480, 20, 15).unwrap();
+        let convolved = resize(&data, 640, 480, 20, 15, "lanczos3", false).unwrap();
+        for (&a, &b) in sampled.iter().zip(&convolved) {
+            assert!(a.abs_diff(b) <= 4, "{a} != {b}");
+        }
+
+        // The fractional part of the crop box isn't rounded in the reduced image,
+        // so the rows are filtered as by the plain convolution.
+        let rows: Vec<u8> = (0..640 * 48 * 4)
+            .map(|i| if i % 4 == 3 { 255 } else { (i / 4 / 640 * 5) as u8 })
+            .collect();
+        resizer.set_crop(0.0, 0.0, 640.0, 47.5);
+        let mut convolver = Resizer::with_algorithm("lanczos3").unwrap();
+        convolver.set_crop(0.0, 0.0, 640.0, 47.5);
+        assert_eq!(
+            resizer.resize_rgba(&rows, 640, 48, 20, 24).unwrap(),
+            convolver.resize_rgba(&rows, 640, 48, 20, 24).unwrap()
+        );
+        resizer.clear_crop();
+
+        // Small downscales are plain convolutions.
+        let sampled = resizer.resize_rgba(&data, 640, 480, 400, 300).unwrap();
+        assert_eq!(sampled, resize(&data, 640, 480, 400, 300, "lanczos3", false).unwrap());
+
+        assert_eq!(
+            Resizer::with_algorithm("supersampling:lanczos3").err(),
+            Some(ResizeError::UnknownFilterType)
+        );
+        assert_eq!(
+            Resizer::with_algorithm("supersampling:lanczos3:0").err(),
+            Some(ResizeError::ZeroMultiplicity)
+        );
+        resizer.algorithm = ResizeAlg::SuperSampling(FilterType::Lanczos3, 0);
+        assert_eq!(
+            resizer.resize_rgba(&data, 640, 480, 20, 15),
+            Err(ResizeError::ZeroMultiplicity)
+        );
+    }
+
+    #[test]
+    fn test_resize_region() {
+        let data = noise(67 * 45 * 4);
+
+        // Bands of rows make up the whole image, also with the supersampling
+        // which reduces only the rows used by a band.
+        for (algorithm, height) in [("lanczos3", 23), ("supersampling:lanczos3:2", 13)] {
+            let mut resizer = Resizer::with_algorithm(algorithm).unwrap();
+            resizer.set_crop(3.5, 2.25, 40.5, 30.0);
+            let full = resizer.resize_rgba(&data, 67, 45, 29, height).unwrap();
+            let bands: Vec<u8> = [0..7, 7..8, 8..height]
+                .into_iter()
+                .flat_map(|rows| {
+                    resizer.resize_rgba_region(&data, 67, 45, 29, height, 0, rows.start, 29, rows.len()).unwrap()
+                })
+                .collect();
+            assert_eq!(bands, full, "{algorithm}");
+        }
+        let mut resizer = Resizer::new("lanczos3".parse().unwrap());
+
+        // Other pixel types, including packed pixels with dithering.
+        resizer.set_dithering(true);
+        for pixel_type in [PixelType::U8, PixelType::U8x3, PixelType::U16x4, PixelType::Rgb565] {
+            let size = pixel_type.size();
+            let mut src = Image::new(33, 21, pixel_type);
+            src.buffer_mut().copy_from_slice(&data[..33 * 21 * size]);
+            let mut full = Image::new(17, 13, pixel_type);
+            resizer.resize(&src.view(), &mut full.view_mut()).unwrap();
+            let mut region = Image::new(9, 5, pixel_type);
+            resizer.resize_region(&src.view(), &mut region.view_mut(), 17, 13, 6, 7).unwrap();
+            assert_eq!(region.buffer(), region_of(full.buffer(), 17, size, (6, 7, 9, 5)), "{pixel_type:?}");
+        }
+    }
+
+    #[test]
+    fn test_cpu_extensions() {
+        // SIMD kernels must produce the same results as the scalar ones.
+        let mut extensions = vec![
+            CpuExtensions::None,
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Sse4_1,
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Avx2,
+            #[cfg(target_feature = "simd128")]
+            CpuExtensions::Simd128,
+        ];
+        extensions.retain(CpuExtensions::is_supported);
+        let data = noise(67 * 45 * 4);
+        let data_u16: Vec<u16> = noise(67 * 45 * 8)
+            .chunks_exact(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+            .collect();
+        let data_f32: Vec<f32> = data.iter().map(|&c| c as f32 / 255.).collect();
+        for algorithm in [
+            ResizeAlg::Nearest,
+            ResizeAlg::Convolution(FilterType::Box),
+            ResizeAlg::Convolution(FilterType::Bilinear),
+            ResizeAlg::Convolution(FilterType::Lanczos3),
+        ] {
+            for (w, h) in [(31, 17), (134, 90), (5, 3), (128, 91)] {
+                let mut results = extensions.iter().map(|&e| {
+                    let mut resizer = Resizer::new(algorithm);
+                    resizer.set_cpu_extensions(e).unwrap();
+                    (
+                        resizer.resize_rgba(&data, 67, 45, w, h).unwrap(),
+                        resizer.resize_gray(&data[..67 * 45], 67, 45, w, h).unwrap(),
+                        resizer.resize_gray_alpha(&data[..67 * 45 * 2], 67, 45, w, h).unwrap(),
+                        resizer.resize_rgb(&data[..67 * 45 * 3], 67, 45, w, h).unwrap(),
+                        resizer.resize_rgba_u16(&data_u16, 67, 45, w, h).unwrap(),
+                        resizer.resize_gray_f32(&data_f32[..67 * 45], 67, 45, w, h).unwrap(),
+                        resizer.resize_rgba_f32(&data_f32, 67, 45, w, h).unwrap(),
+                    )
+                });
+                let expected = results.next().unwrap();
+                for result in results {
+                    assert!(result.0 == expected.0, "{algorithm:?} {w}x{h}");
+                    assert!(result.1 == expected.1, "{algorithm:?} {w}x{h}");
+                    assert!(result.2 == expected.2, "{algorithm:?} {w}x{h}");
+                    assert!(result.3 == expected.3, "{algorithm:?} {w}x{h}");
+                    assert!(result.4 == expected.4, "{algorithm:?} {w}x{h}");
+                    // Floats are summed in a different order.
+                    for (a, b) in [(&result.5, &expected.5), (&result.6, &expected.6)] {
+                        assert!(
+                            a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-5),
+                            "{algorithm:?} {w}x{h}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_rayon_bands() {
+        let data = noise(67 * 45 * 4);
+        let data_u16: Vec<u16> = data.iter().map(|&v| v as u16 * 257).collect();
+        let data_f32: Vec<f32> = data.iter().map(|&v| v as f32 / 255.0).collect();
+        let resize_all = |threads: usize| {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+            pool.install(|| {
+                let mut results = Vec::new();
+                for algorithm in ["bilinear", "lanczos3", "supersampling:catmullrom:2"] {
+                    let mut resizer = Resizer::new(algorithm.parse().unwrap());
+                    // Both passes, horizontal pass only and vertical pass only.
+                    for (w, h) in [(31, 17), (20, 45), (67, 91)] {
+                        results.push((
+                            resizer.resize_rgba(&data, 67, 45, w, h).unwrap(),
+                            resizer.resize_rgb(&data[..67 * 45 * 3], 67, 45, w, h).unwrap(),
+                            resizer.resize_rgba_u16(&data_u16, 67, 45, w, h).unwrap(),
+                            resizer.resize_rgba_f32(&data_f32, 67, 45, w, h).unwrap(),
+                        ));
+                    }
+                    resizer.set_crop(3.5, 2.25, 40.0, 30.0);
+                    results.push((
+                        resizer.resize_rgba(&data, 67, 45, 13, 11).unwrap(),
+                        resizer.resize_rgb(&data[..67 * 45 * 3], 67, 45, 13, 11).unwrap(),
+                        resizer.resize_rgba_u16(&data_u16, 67, 45, 13, 11).unwrap(),
+                        resizer.resize_rgba_f32(&data_f32, 67, 45, 13, 11).unwrap(),
+                    ));
+                }
+                results
+            })
+        };
+        let expected = resize_all(1);
+        for threads in [2, 5, 16] {
+            assert!(resize_all(threads) == expected, "{threads} threads");
+        }
     }
 }
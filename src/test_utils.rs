@@ -0,0 +1,38 @@
+//! Images shared by the tests.
+
+/// Buffer of `len` bytes with a repeating gradient.
+pub(crate) fn gradient(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7 % 256) as u8).collect()
+}
+
+/// Rgba buffer of `len` bytes with the gradient in the color channels and opaque pixels,
+/// so that the multiplication by alpha is lossless.
+pub(crate) fn opaque_gradient(len: usize) -> Vec<u8> {
+    (0..len)
+        .map(|i| if i % 4 == 3 { 255 } else { (i * 7 % 256) as u8 })
+        .collect()
+}
+
+/// Buffer of `len` bytes without any pattern, so that the kernels
+/// get every combination of values.
+pub(crate) fn noise(len: usize) -> Vec<u8> {
+    (0..len as u32)
+        .map(|i| (i.wrapping_mul(2654435761) >> 24) as u8)
+        .collect()
+}
+
+/// Rectangle `(left, top, width, height)` of an image with given width,
+/// whose pixels have `pixel_size` bytes.
+pub(crate) fn region_of(
+    image: &[u8],
+    image_width: usize,
+    pixel_size: usize,
+    (left, top, width, height): (usize, usize, usize, usize),
+) -> Vec<u8> {
+    image
+        .chunks_exact(image_width * pixel_size)
+        .skip(top)
+        .take(height)
+        .flat_map(|row| row[left * pixel_size..(left + width) * pixel_size].iter().copied())
+        .collect()
+}
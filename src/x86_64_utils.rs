@@ -0,0 +1,77 @@
+use std::arch::x86_64::*;
+
+use crate::pixels::U8x4;
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn loadu_si128<T>(buf: &[T], index: usize) -> __m128i {
+    unsafe {
+        _mm_loadu_si128(buf.get_unchecked(index..).as_ptr() as *const __m128i)
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn loadl_epi64<T>(buf: &[T], index: usize) -> __m128i {
+    unsafe {
+        _mm_loadl_epi64(buf.get_unchecked(index..).as_ptr() as *const __m128i)
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn loadl_epi32<T>(buf: &[T], index: usize) -> __m128i {
+    unsafe {
+        let p = buf.get_unchecked(index..).as_ptr() as *const i32;
+        _mm_cvtsi32_si128(p.read_unaligned())
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn ptr_i16_to_set1_epi32(buf: &[i16], index: usize) -> __m128i {
+    unsafe {
+        let p = buf.get_unchecked(index..).as_ptr() as *const i32;
+        _mm_set1_epi32(p.read_unaligned())
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn mm_cvtepu8_epi32_u8x4(buf: &[U8x4], index: usize) -> __m128i {
+    unsafe {
+        let v = i32::from_le_bytes(buf.get_unchecked(index).0);
+        _mm_cvtepu8_epi32(_mm_cvtsi32_si128(v))
+    }
+}
+
+/// Returns the four lowest bytes of the register as a pixel.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn mm_cvtsi128_u8x4(v: __m128i) -> U8x4 {
+    U8x4::new(_mm_cvtsi128_si32(v).to_le_bytes())
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn loadu_si256<T>(buf: &[T], index: usize) -> __m256i {
+    unsafe {
+        _mm256_loadu_si256(buf.get_unchecked(index..).as_ptr() as *const __m256i)
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn ptr_i16_to_256set1_epi32(buf: &[i16], index: usize) -> __m256i {
+    unsafe {
+        let p = buf.get_unchecked(index..).as_ptr() as *const i32;
+        _mm256_set1_epi32(p.read_unaligned())
+    }
+}
+
+/// Returns the sum of the lower and the upper 128-bit lanes of the register.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn mm256_hadd_lanes_epi32(v: __m256i) -> __m128i {
+    _mm_add_epi32(_mm256_castsi256_si128(v), _mm256_extracti128_si256::<1>(v))
+}